@@ -0,0 +1,72 @@
+//! Splash screen that waits for assets to load
+//!
+//! The logo is displayed for at least its fade duration, and for
+//! however longer it takes to load the textures for the main menu.
+//! If any of them fail to load, an error is logged, and the main
+//! menu is displayed anyway.
+
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+struct MenuAssets {
+    background: Handle<Image>,
+    logo: Handle<Image>,
+}
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins);
+
+    // use your own images here
+    let server = app.world.resource::<AssetServer>();
+    let assets = MenuAssets {
+        background: server.load("menu/background.png"),
+        logo: server.load("menu/logo.png"),
+    };
+    let handles = [
+        assets.background.clone_untyped(),
+        assets.logo.clone_untyped(),
+    ];
+
+    app.insert_resource(assets)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(
+            SplashPlugin::new(AppState::Splash, AppState::MainMenu)
+                .wait_for_assets(handles)
+        )
+        .add_enter_system(AppState::Splash, setup_splash)
+        .add_enter_system(AppState::MainMenu, setup_menu)
+        .run();
+}
+
+fn setup_splash(mut commands: Commands) {
+    commands.spawn_splash_item(SpriteBundle {
+        sprite: Sprite {
+            color: Color::ORANGE,
+            custom_size: Some(Vec2::new(256.0, 256.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .insert(SplashItemFade::standard());
+}
+
+fn setup_menu(mut commands: Commands, assets: Res<MenuAssets>) {
+    commands.spawn_bundle(Camera2dBundle::default());
+    commands.spawn_bundle(SpriteBundle {
+        texture: assets.background.clone(),
+        ..Default::default()
+    });
+    commands.spawn_bundle(SpriteBundle {
+        texture: assets.logo.clone(),
+        transform: Transform::from_xyz(0.0, 0.0, 1.0),
+        ..Default::default()
+    });
+}
@@ -0,0 +1,50 @@
+//! Splash screen with an audio sting playing alongside a logo
+//!
+//! Put your own sound file at `assets/sting.ogg`, and run with:
+//!
+//! ```sh
+//! cargo run --example audio --features audio,bevy/vorbis
+//! ```
+
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(SplashPlugin::new(AppState::Splash, AppState::MainMenu))
+        .add_enter_system(AppState::Splash, setup_splash)
+        .add_enter_system(AppState::MainMenu, main_menu)
+        .run();
+}
+
+fn setup_splash(mut commands: Commands, assets: Res<AssetServer>) {
+    // use your own logo image here
+    commands.spawn_bundle(SpriteBundle {
+        sprite: Sprite {
+            color: Color::ORANGE,
+            custom_size: Some(Vec2::new(256.0, 256.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .insert(SplashCleanup)
+    .insert(SplashItemFade::new(0.0, 0.5, 1.5, 0.5));
+
+    // the splash screen will last until the sound has finished, too
+    commands.spawn()
+        .insert(SplashCleanup)
+        .insert(SplashItemAudio::new(assets.load("sting.ogg"), 3.0));
+}
+
+fn main_menu() {
+    info!("Splash screen done!");
+}
@@ -0,0 +1,44 @@
+//! Splash screen with a solid black background
+//!
+//! The rest of the app keeps the default clear color.
+
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(
+            SplashPlugin::new(AppState::Splash, AppState::MainMenu)
+                .background(Color::BLACK)
+        )
+        .add_enter_system(AppState::Splash, setup_splash)
+        .add_enter_system(AppState::MainMenu, setup_menu)
+        .run();
+}
+
+fn setup_splash(mut commands: Commands) {
+    // use your own logo image here
+    commands.spawn_bundle(SpriteBundle {
+        sprite: Sprite {
+            color: Color::WHITE,
+            custom_size: Some(Vec2::new(256.0, 256.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .insert(SplashCleanup)
+    .insert(SplashItemFade::new(0.5, 1.0, 2.0, 1.0));
+}
+
+fn setup_menu(mut commands: Commands) {
+    commands.spawn_bundle(Camera2dBundle::default());
+}
@@ -0,0 +1,42 @@
+//! Splash screen using Bevy's own states, instead of `iyes_loopless`
+//!
+//! Run with `cargo run --example bevy_states --no-default-features`.
+
+use bevy::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_state(AppState::Splash)
+        .add_plugin(SplashPlugin::new(AppState::Splash, AppState::MainMenu))
+        .add_system_set(SystemSet::on_enter(AppState::Splash).with_system(setup_splash))
+        .add_system_set(SystemSet::on_enter(AppState::MainMenu).with_system(main_menu))
+        .run();
+}
+
+fn setup_splash(mut commands: Commands) {
+    commands.spawn_bundle(Camera2dBundle::default())
+        .insert(SplashCleanup);
+    // use your own logo image here
+    commands.spawn_bundle(SpriteBundle {
+        sprite: Sprite {
+            color: Color::ORANGE,
+            custom_size: Some(Vec2::new(256.0, 256.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .insert(SplashCleanup)
+    .insert(SplashItemFade::new(0.5, 1.0, 2.0, 1.0));
+}
+
+fn main_menu() {
+    info!("Splash screen done!");
+}
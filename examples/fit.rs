@@ -0,0 +1,40 @@
+//! Splash screen with a logo scaled to fit the window
+//!
+//! Try resizing the window: the logo always fits inside it, keeping
+//! its aspect ratio, while the background image covers all of it.
+
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(SplashPlugin::new(AppState::Splash, AppState::MainMenu))
+        .add_enter_system(AppState::Splash, setup_splash)
+        .run();
+}
+
+fn setup_splash(mut commands: Commands, server: Res<AssetServer>) {
+    // use your own images here
+    commands.spawn_splash_item(SpriteBundle {
+        texture: server.load("splash/background.png"),
+        ..Default::default()
+    })
+    .insert(SplashItemFit::Cover)
+    .insert(SplashItemFade::new(0.0, 0.5, 4.0, 0.5));
+    commands.spawn_splash_item(SpriteBundle {
+        texture: server.load("splash/logo.png"),
+        transform: Transform::from_xyz(0.0, 0.0, 1.0),
+        ..Default::default()
+    })
+    .insert(SplashItemFit::Contain)
+    .insert(SplashItemFade::new(0.5, 1.0, 2.0, 1.0));
+}
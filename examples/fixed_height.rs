@@ -0,0 +1,42 @@
+//! Splash screen with logos sized relative to the window height
+//!
+//! The splash screen camera always shows 1000 world units vertically,
+//! so the logo (500 units tall) always covers half of the window's
+//! height. Try resizing the window: unlike with the default camera,
+//! the logo grows and shrinks along with it.
+
+use bevy::prelude::*;
+use bevy::render::camera::ScalingMode;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(
+            SplashPlugin::new(AppState::Splash, AppState::MainMenu)
+                .camera_scaling(ScalingMode::FixedVertical(1000.0))
+        )
+        .add_enter_system(AppState::Splash, setup_splash)
+        .run();
+}
+
+fn setup_splash(mut commands: Commands) {
+    // use your own logo image here, with a custom size in world units
+    commands.spawn_splash_item(SpriteBundle {
+        sprite: Sprite {
+            color: Color::ORANGE,
+            custom_size: Some(Vec2::new(500.0, 500.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .insert(SplashItemFade::standard());
+}
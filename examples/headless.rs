@@ -0,0 +1,50 @@
+//! Stepping through a splash screen deterministically, without a window
+//!
+//! This is how you could test your splash screen setup: every
+//! `app.update()` advances the splash screen by exactly 100ms,
+//! so we know exactly on which frame it should end.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(bevy::input::InputPlugin)
+        .insert_resource(SplashTestClock(Duration::from_millis(100)))
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(
+            SplashPlugin::new(AppState::Splash, AppState::MainMenu)
+                .spawn_camera(false)
+        )
+        .add_enter_system(AppState::Splash, setup_splash);
+
+    // the state is entered on the first update, which also runs the first
+    // frame of the splash screen. The fade lasts 1 second in total, so
+    // it finishes on the 10th frame, and the splash screen ends on the
+    // 11th, once the fade has stayed finished for a whole frame.
+    for _ in 1..=11 {
+        app.update();
+        let state = app.world.resource::<CurrentState<AppState>>().0;
+        assert_eq!(state, AppState::Splash);
+    }
+
+    // the transition is applied at the start of the next update
+    app.update();
+    let state = app.world.resource::<CurrentState<AppState>>().0;
+    assert_eq!(state, AppState::MainMenu);
+}
+
+fn setup_splash(mut commands: Commands) {
+    // (durations that are exact in floating point, for exact frame counts)
+    commands.spawn_splash_item((SplashItemFade::new(0.0, 0.25, 0.5, 0.25),));
+}
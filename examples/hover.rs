@@ -0,0 +1,33 @@
+//! Splash screen with a logo that lights up when the mouse is near it
+//!
+//! Move the cursor towards the logo: it gets brighter and slightly
+//! larger. This is only for show, the splash screen still ends on time.
+
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(SplashPlugin::new(AppState::Splash, AppState::MainMenu))
+        .add_enter_system(AppState::Splash, setup_splash)
+        .run();
+}
+
+fn setup_splash(mut commands: Commands, server: Res<AssetServer>) {
+    // use your own image here
+    commands.spawn_splash_item(SpriteBundle {
+        texture: server.load("splash/logo.png"),
+        ..Default::default()
+    })
+    .insert(SplashItemFade::new(0.5, 1.0, 6.0, 1.0))
+    .insert(SplashItemHover::new(300.0).with_alpha(0.5).with_scale(1.1));
+}
@@ -0,0 +1,41 @@
+//! Splash screen with a logo drawn on top of a background
+//!
+//! Both sprites are spawned at the same position, and their layers
+//! decide which one is in front, regardless of the order they
+//! are spawned in.
+
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(SplashPlugin::new(AppState::Splash, AppState::MainMenu))
+        .add_enter_system(AppState::Splash, setup_splash)
+        .run();
+}
+
+fn setup_splash(mut commands: Commands, server: Res<AssetServer>) {
+    // use your own images here
+    commands.spawn_splash_item(SpriteBundle {
+        texture: server.load("splash/logo.png"),
+        ..Default::default()
+    })
+    .insert(SplashLayer(1))
+    .insert(SplashItemFade::new(0.5, 1.0, 2.0, 1.0));
+    commands.spawn_splash_item(SpriteBundle {
+        texture: server.load("splash/background.png"),
+        ..Default::default()
+    })
+    .insert(SplashLayer(0))
+    .insert(SplashItemFit::Cover)
+    .insert(SplashItemFade::new(0.0, 0.5, 4.0, 0.5));
+}
@@ -0,0 +1,61 @@
+//! Splash screen displayed on top of an existing scene
+//!
+//! The scene is spawned at startup, with its own camera, and stays
+//! visible underneath the splash screen, which does not clear it.
+//!
+//! The splash screen camera and logo use a separate render layer, so
+//! that each camera only renders its own entities.
+
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    InGame,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(
+            SplashPlugin::new(AppState::Splash, AppState::InGame)
+                .clear(false)
+                .camera_layers(RenderLayers::layer(1))
+        )
+        .add_startup_system(setup_scene)
+        .add_enter_system(AppState::Splash, setup_splash)
+        .run();
+}
+
+fn setup_scene(mut commands: Commands) {
+    commands.spawn_bundle(Camera2dBundle::default());
+    for i in 0..5 {
+        commands.spawn_bundle(SpriteBundle {
+            sprite: Sprite {
+                color: Color::hsl(i as f32 * 72.0, 0.5, 0.5),
+                custom_size: Some(Vec2::new(100.0, 100.0)),
+                ..Default::default()
+            },
+            transform: Transform::from_xyz(-300.0 + i as f32 * 150.0, 0.0, 0.0),
+            ..Default::default()
+        });
+    }
+}
+
+fn setup_splash(mut commands: Commands) {
+    // use your own logo image here
+    commands.spawn_splash_item(SpriteBundle {
+        sprite: Sprite {
+            color: Color::WHITE,
+            custom_size: Some(Vec2::new(256.0, 256.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .insert(RenderLayers::layer(1))
+    .insert(SplashItemFade::standard());
+}
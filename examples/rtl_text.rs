@@ -0,0 +1,48 @@
+//! Splash screen with localized studio text
+//!
+//! Run with `LANG=ar` (or any other right-to-left language) to see the
+//! Arabic variant, aligned to the right. Otherwise, the English variant
+//! is displayed, aligned to the left. Both fade in and out.
+
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(SplashPlugin::new(AppState::Splash, AppState::MainMenu))
+        .add_enter_system(AppState::Splash, setup_splash)
+        .run();
+}
+
+fn setup_splash(mut commands: Commands, server: Res<AssetServer>) {
+    let locale = std::env::var("LANG").unwrap_or_default();
+    let direction = SplashTextDirection::from_locale(&locale);
+    // the Arabic text mixes in the studio name, in English
+    let text = match direction {
+        SplashTextDirection::RightToLeft => "استوديو Iyes Games\nيقدم",
+        _ => "Iyes Games Studio\npresents",
+    };
+    // use a font that covers Arabic here
+    let style = TextStyle {
+        font: server.load("fonts/NotoSansArabic-Regular.ttf"),
+        font_size: 48.0,
+        color: Color::WHITE,
+    };
+    // put the start of the text a bit away from the edge of the window
+    let x = match direction {
+        SplashTextDirection::RightToLeft => 300.0,
+        _ => -300.0,
+    };
+    commands.spawn_splash_text(text, style, direction)
+        .insert(Transform::from_xyz(x, 0.0, 0.0))
+        .insert(SplashItemFade::standard());
+}
@@ -0,0 +1,53 @@
+//! Waiting for a splash screen from an async task
+//!
+//! A background task is started right away, and awaits the splash
+//! screen ending, before starting its own (pretend) work.
+
+use bevy::prelude::*;
+use bevy::tasks::AsyncComputeTaskPool;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    let signal = SplashSignal::new();
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(
+            SplashPlugin::new(AppState::Splash, AppState::MainMenu)
+                .signal(signal.clone())
+        )
+        .insert_resource(signal)
+        .add_startup_system(start_task)
+        .add_enter_system(AppState::Splash, setup_splash)
+        .run();
+}
+
+fn start_task(signal: Res<SplashSignal>) {
+    let signal = signal.clone();
+    AsyncComputeTaskPool::get()
+        .spawn(async move {
+            let reason = signal.wait().await;
+            info!("Splash screen ended ({:?}), starting background work!", reason);
+        })
+        .detach();
+}
+
+fn setup_splash(mut commands: Commands) {
+    // use your own logo image here
+    commands.spawn_splash_item(SpriteBundle {
+        sprite: Sprite {
+            color: Color::ORANGE,
+            custom_size: Some(Vec2::new(256.0, 256.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .insert(SplashItemFade::standard());
+}
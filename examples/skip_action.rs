@@ -0,0 +1,75 @@
+//! Skipping with your own input handling, instead of raw device input
+//!
+//! A tiny stand-in for an input mapping crate: the `Skip` action is bound
+//! to Escape and to the gamepad's Start button, and updated every frame.
+//! With `leafwing-input-manager`, you would check your `ActionState`
+//! in the same way instead. Other keys and buttons do not skip.
+
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+/// Our "action map", with a single action
+#[derive(Default)]
+struct Actions {
+    skip_just_pressed: bool,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .init_resource::<Actions>()
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(
+            SplashPlugin::new(AppState::Splash, AppState::MainMenu)
+                .skip_when(|world| world.resource::<Actions>().skip_just_pressed)
+        )
+        // input mapping crates update their actions before `CoreStage::Update`
+        .add_system_to_stage(CoreStage::PreUpdate, update_actions)
+        .add_enter_system(AppState::Splash, setup_splash)
+        .add_enter_system(AppState::MainMenu, setup_menu)
+        .run();
+}
+
+fn update_actions(
+    mut actions: ResMut<Actions>,
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    gamepads: Res<Gamepads>,
+) {
+    let start = gamepads.iter().any(|gamepad| {
+        buttons.just_pressed(GamepadButton::new(*gamepad, GamepadButtonType::Start))
+    });
+    actions.skip_just_pressed = keys.just_pressed(KeyCode::Escape) || start;
+}
+
+fn setup_splash(mut commands: Commands) {
+    // use your own logo image here
+    commands.spawn_splash_item(SpriteBundle {
+        sprite: Sprite {
+            color: Color::ORANGE,
+            custom_size: Some(Vec2::new(256.0, 256.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .insert(SplashItemFade::new(0.5, 1.0, 3.0, 0.5));
+}
+
+fn setup_menu(mut commands: Commands) {
+    commands.spawn_bundle(Camera2dBundle::default());
+    commands.spawn_bundle(SpriteBundle {
+        sprite: Sprite {
+            color: Color::WHITE,
+            custom_size: Some(Vec2::new(512.0, 64.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+}
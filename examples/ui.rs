@@ -0,0 +1,53 @@
+//! Splash screen made with Bevy UI
+//!
+//! Displays a logo in the center of the window, using flexbox layout.
+
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(SplashPlugin::new(AppState::Splash, AppState::MainMenu))
+        .add_enter_system(AppState::Splash, setup_splash)
+        .add_enter_system(AppState::MainMenu, main_menu)
+        .run();
+}
+
+fn setup_splash(mut commands: Commands) {
+    commands.spawn_bundle(NodeBundle {
+        style: Style {
+            size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        },
+        color: Color::NONE.into(),
+        ..Default::default()
+    })
+    .insert(SplashCleanup)
+    .with_children(|parent| {
+        // use your own logo image here
+        parent.spawn_bundle(ImageBundle {
+            style: Style {
+                size: Size::new(Val::Px(256.0), Val::Px(256.0)),
+                ..Default::default()
+            },
+            color: Color::ORANGE.into(),
+            ..Default::default()
+        })
+        .insert(SplashItemFade::new(0.5, 1.0, 2.0, 1.0));
+    });
+}
+
+fn main_menu() {
+    info!("Splash screen done!");
+}
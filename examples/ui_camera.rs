@@ -0,0 +1,86 @@
+//! Splash screen with separate cameras for sprites and UI
+//!
+//! The logo sprite is rendered by a camera that only sees render
+//! layer 1, and the UI is rendered on top of it by a dedicated camera.
+
+use bevy::core_pipeline::clear_color::ClearColorConfig;
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(
+            SplashPlugin::new(AppState::Splash, AppState::MainMenu)
+                .camera_setup(setup_cameras)
+        )
+        .add_enter_system(AppState::Splash, setup_splash)
+        .add_enter_system(AppState::MainMenu, main_menu)
+        .run();
+}
+
+fn setup_cameras(commands: &mut Commands) {
+    // sprites only
+    commands.spawn_bundle(Camera2dBundle::default())
+        .insert(RenderLayers::layer(1))
+        .insert(UiCameraConfig { show_ui: false })
+        .insert(SplashCleanup);
+    // UI only, drawn on top of the other camera
+    commands.spawn_bundle(Camera2dBundle {
+        camera: Camera {
+            priority: 1,
+            ..Default::default()
+        },
+        camera_2d: Camera2d {
+            clear_color: ClearColorConfig::None,
+        },
+        ..Default::default()
+    })
+    .insert(RenderLayers::layer(2))
+    .insert(SplashCleanup);
+}
+
+fn setup_splash(mut commands: Commands) {
+    // use your own logo image here
+    commands.spawn_bundle(SpriteBundle {
+        sprite: Sprite {
+            color: Color::ORANGE,
+            custom_size: Some(Vec2::new(256.0, 256.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .insert(RenderLayers::layer(1))
+    .insert(SplashCleanup)
+    .insert(SplashItemFade::new(0.5, 1.0, 2.0, 1.0));
+
+    // a bar along the bottom of the window
+    commands.spawn_bundle(NodeBundle {
+        style: Style {
+            size: Size::new(Val::Percent(100.0), Val::Px(16.0)),
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                bottom: Val::Px(0.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        color: Color::GRAY.into(),
+        ..Default::default()
+    })
+    .insert(SplashCleanup)
+    .insert(SplashItemFade::new(0.0, 1.0, 2.5, 1.0));
+}
+
+fn main_menu() {
+    info!("Splash screen done!");
+}
@@ -0,0 +1,75 @@
+//! Splash screen that waits for a flag in a resource
+//!
+//! Simulates a network handshake, that takes a few seconds to complete.
+//! The logo is displayed for at least its fade duration, and for however
+//! longer it takes for the handshake to be done. Skipping still works
+//! immediately.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+use iyes_splash::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    Splash,
+    MainMenu,
+}
+
+#[derive(Default)]
+struct Handshake {
+    done: bool,
+    elapsed: Duration,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .init_resource::<Handshake>()
+        .add_loopless_state(AppState::Splash)
+        .add_plugin(
+            SplashPlugin::new(AppState::Splash, AppState::MainMenu)
+                .wait_until(|world| world.resource::<Handshake>().done)
+        )
+        .add_enter_system(AppState::Splash, setup_splash)
+        .add_system(fake_handshake)
+        .add_enter_system(AppState::MainMenu, setup_menu)
+        .run();
+}
+
+fn setup_splash(mut commands: Commands) {
+    commands.spawn_splash_item(SpriteBundle {
+        sprite: Sprite {
+            color: Color::ORANGE,
+            custom_size: Some(Vec2::new(256.0, 256.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .insert(SplashItemFade::quick());
+}
+
+/// Pretend that we are talking to a server
+fn fake_handshake(mut handshake: ResMut<Handshake>, time: Res<Time>) {
+    if handshake.done {
+        return;
+    }
+    handshake.elapsed += time.delta();
+    if handshake.elapsed >= Duration::from_secs(4) {
+        info!("Handshake done!");
+        handshake.done = true;
+    }
+}
+
+fn setup_menu(mut commands: Commands) {
+    commands.spawn_bundle(Camera2dBundle::default());
+    commands.spawn_bundle(SpriteBundle {
+        sprite: Sprite {
+            color: Color::WHITE,
+            custom_size: Some(Vec2::new(512.0, 64.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+}
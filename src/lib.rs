@@ -1,8 +1,27 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::Duration;
+
 use bevy::prelude::*;
 
-use bevy::ecs::schedule::StateData;
+use bevy::ecs::event::ManualEventReader;
+use bevy::ecs::schedule::{IntoSystemDescriptor, StageLabelId, StateData};
+use bevy::ecs::system::{EntityCommands, SystemParam};
 use bevy::input::keyboard::KeyboardInput;
 use bevy::input::mouse::MouseButtonInput;
+use bevy::render::camera::ScalingMode;
+use bevy::render::view::RenderLayers;
+use bevy::utils::Instant;
+use bevy::utils::tracing::Span;
+use bevy::window::{WindowCloseRequested, WindowFocused, WindowResized};
+
+#[cfg(feature = "audio")]
+use bevy::audio::AudioSink;
+
+#[cfg(feature = "iyes_loopless")]
+use iyes_loopless::prelude::*;
 
 /// Plugin to add a simple splash-screen state
 ///
@@ -10,13 +29,26 @@ use bevy::input::mouse::MouseButtonInput;
 /// state during which to display it, and the next app
 /// state to transition to. You can create as many
 /// instances of this plugin as you need, if you want
-/// multiple splash screens.
+/// multiple splash screens, each in its own state.
+/// Only one splash screen plugin can be added for each
+/// state: if there is another one for the same state, the
+/// new one is ignored, and an error is logged.
 ///
 /// You are expected to populate the splash screen with
 /// whatever you want to display, yourself. Just spawn
 /// entities and insert one of the following components:
 ///  - [`SplashItemTimeout`]
 ///  - [`SplashItemFade`]
+///  - [`SplashItemScale`]
+///  - [`SplashItemMove`]
+///  - [`SplashItemBlink`]
+///  - `SplashItemAudio` (with the `audio` cargo feature)
+///
+/// Also insert [`SplashCleanup`] on them, so they get
+/// despawned when the splash screen ends.
+///
+/// All items play at the same time, unless you
+/// sequence them using [`SplashItemOrder`].
 ///
 /// When the [`Timer`][bevy::time::Timer]s inside all
 /// such components have completed (all splash screen
@@ -26,32 +58,662 @@ use bevy::input::mouse::MouseButtonInput;
 /// The splash screen is skippable by the user, by default.
 /// Any of the following input events will cause the
 /// state transition to be performed immediately:
-///  - any keyboard keypress
+///  - any keyboard keypress (or only some keys, see `.skip_keys(...)`)
 ///  - any mouse button press
 ///  - any gamepad button press
 ///  - any started touchscreen touch
 ///
-/// To disable this behavior, set `skippable` to `false`.
+/// Input is ignored until at least one splash item exists,
+/// so that nothing is skipped if you spawn your items late.
+///
+/// A [`SplashSkipped`] event is sent when that happens.
+/// Either way, a [`SplashCompleted`] event is sent when
+/// the splash screen ends, telling you the reason.
+///
+/// The input that skipped the splash screen is consumed when
+/// it ends, so it does not also get handled by the next state:
+/// pending input events are dropped, and [`Input`] will not
+/// report anything as "just pressed".
+///
+/// To disable this behavior, use `.skippable(false)`.
+/// You can also disable individual input sources, using
+/// `.skip_keyboard(false)`, `.skip_mouse(false)`, etc.
+/// To only disable it for a short while after the splash
+/// screen starts, use `.skip_after(duration)`, or
+/// `.skip_once_visible(true)` to wait until something is shown.
 ///
 /// If you would like to perform other background work
 /// during your splash screen (such as loading assets,
 /// etc.), consider using [`SplashProgressPlugin`]
 /// instead (with the `iyes_progress` cargo feature).
+///
+/// To decide the next state yourself, such as after some work
+/// that finishes at an unknown time, create the plugin with
+/// [`SplashPlugin::manual`] instead. In this "manual transition"
+/// mode, no transition is performed when the splash screen ends
+/// (whether it completed, was skipped, or timed out): only the
+/// [`SplashCompleted`] event is sent, and the splash screen stays
+/// in its final state (with its items, and any exit fade, still
+/// displayed), until you transition to another state yourself.
+///
+/// By default, `state` and `next` are `iyes_loopless` states
+/// (added with `add_loopless_state`, which must be done before adding
+/// the plugin, or it panics). To use Bevy's own
+/// [`State`] (added with `add_state`) instead, disable
+/// the default `iyes_loopless` cargo feature.
 pub struct SplashPlugin<S: StateData> {
-    pub state: S,
-    pub next: S,
-    pub skippable: bool,
+    state: S,
+    next: Option<S>,
+    options: SplashOptions,
+    return_to_previous: bool,
+    max_duration: Option<Duration>,
+    assets: Vec<HandleUntyped>,
+    conditions: Vec<SplashCondition>,
+    skip_waits_for_condition: bool,
+}
+
+/// The options shared by [`SplashPlugin`] and [`SplashProgressPlugin`]
+///
+/// Only set through the builder methods, see `splash_builder_methods`.
+struct SplashOptions {
+    skippable: bool,
+    skip_after: Duration,
+    skip_once_visible: bool,
+    min_duration: Duration,
+    skip_keys: Option<Vec<KeyCode>>,
+    skip_keyboard: bool,
+    skip_mouse: bool,
+    skip_gamepad: bool,
+    gamepad_threshold: f32,
+    skip_touch: bool,
+    hold_to_skip: Option<Duration>,
+    confirm_skip: Option<Duration>,
+    skip_mode: SkipMode,
+    skip_when: Option<SplashCondition>,
+    background: Option<Color>,
+    clear: bool,
+    spawn_camera: bool,
+    camera_layers: Option<RenderLayers>,
+    camera_scaling: Option<ScalingMode>,
+    camera_setup: Option<SplashCameraSetup>,
+    clock: SplashClock,
+    focus_loss: SplashFocusLoss,
+    on_complete: Vec<SplashCompleteHook>,
+    signal: Option<SplashSignal>,
+    exit_fade: Option<SplashExitFade>,
+    screen_fade: Option<SplashScreenFade>,
+    require_interaction: bool,
+    #[cfg(feature = "audio")]
+    music_fade_out: Option<Duration>,
+    stage: StageLabelId,
+}
+
+impl Default for SplashOptions {
+    fn default() -> Self {
+        SplashOptions {
+            skippable: true,
+            skip_after: Duration::ZERO,
+            skip_once_visible: false,
+            min_duration: Duration::ZERO,
+            skip_keys: None,
+            skip_keyboard: true,
+            skip_mouse: true,
+            skip_gamepad: true,
+            gamepad_threshold: 0.5,
+            skip_touch: true,
+            hold_to_skip: None,
+            confirm_skip: None,
+            skip_mode: SkipMode::Whole,
+            skip_when: None,
+            background: None,
+            clear: true,
+            spawn_camera: true,
+            camera_layers: None,
+            camera_scaling: None,
+            camera_setup: None,
+            clock: SplashClock::Real,
+            focus_loss: SplashFocusLoss::Ignore,
+            on_complete: Vec::new(),
+            signal: None,
+            exit_fade: None,
+            screen_fade: None,
+            require_interaction: false,
+            #[cfg(feature = "audio")]
+            music_fade_out: None,
+            stage: CoreStage::Update.as_label(),
+        }
+    }
+}
+
+impl SplashOptions {
+    /// The settings for a splash screen with these options
+    ///
+    /// The settings that only [`SplashPlugin`] has are left empty.
+    fn config(&self) -> SplashConfig {
+        SplashConfig {
+            skippable: self.skippable,
+            skip_after: self.skip_after,
+            skip_once_visible: self.skip_once_visible,
+            min_duration: self.min_duration,
+            skip_keys: self.skip_keys.clone(),
+            skip_keyboard: self.skip_keyboard,
+            skip_mouse: self.skip_mouse,
+            skip_gamepad: self.skip_gamepad,
+            gamepad_threshold: self.gamepad_threshold,
+            skip_touch: self.skip_touch,
+            hold_to_skip: self.hold_to_skip,
+            confirm_skip: self.confirm_skip,
+            skip_mode: self.skip_mode,
+            skip_when: self.skip_when.clone(),
+            background: self.background,
+            clear: self.clear,
+            spawn_camera: self.spawn_camera,
+            camera_layers: self.camera_layers,
+            camera_scaling: self.camera_scaling.clone(),
+            camera_setup: self.camera_setup.clone(),
+            clock: self.clock,
+            focus_loss: self.focus_loss,
+            on_complete: self.on_complete.clone(),
+            signal: self.signal.clone(),
+            exit_fade: self.exit_fade.or_else(|| {
+                self.screen_fade
+                    .filter(|fade| !fade.fade_out.is_zero())
+                    .map(|fade| SplashExitFade::new(fade.fade_out).with_color(fade.color))
+            }),
+            screen_fade: self.screen_fade.filter(|fade| !fade.fade_in.is_zero()),
+            require_interaction: self.require_interaction,
+            #[cfg(feature = "audio")]
+            music_fade_out: self.music_fade_out,
+            stage: self.stage,
+            max_duration: None,
+            assets: Vec::new(),
+            conditions: self.require_interaction.then(splash_interaction_condition).into_iter().collect(),
+            skip_waits_for_condition: false,
+            skip_all: false,
+            span: Span::none(),
+        }
+    }
+}
+
+/// Builder methods for the options shared by [`SplashPlugin`] and [`SplashProgressPlugin`]
+///
+/// Both plugins keep them in [`SplashOptions`], so they are documented
+/// (and implemented) only once, here.
+macro_rules! splash_builder_methods {
+    () => {
+        /// Set whether the user can skip the splash screen
+        ///
+        /// This also enables/disables every individual input source.
+        pub fn skippable(mut self, skippable: bool) -> Self {
+            self.options.skippable = skippable;
+            self.options.skip_keyboard = skippable;
+            self.options.skip_mouse = skippable;
+            self.options.skip_gamepad = skippable;
+            self.options.skip_touch = skippable;
+            self
+        }
+
+        /// Set whether keyboard input can skip the splash screen
+        pub fn skip_keyboard(mut self, enable: bool) -> Self {
+            self.options.skip_keyboard = enable;
+            self
+        }
+
+        /// Set whether mouse input can skip the splash screen
+        pub fn skip_mouse(mut self, enable: bool) -> Self {
+            self.options.skip_mouse = enable;
+            self
+        }
+
+        /// Set whether gamepad input can skip the splash screen
+        pub fn skip_gamepad(mut self, enable: bool) -> Self {
+            self.options.skip_gamepad = enable;
+            self
+        }
+
+        /// How far a gamepad button must be pressed, to skip the splash screen
+        ///
+        /// In the `0.0..=1.0` range. Analog buttons (such as triggers) report
+        /// how far they are pressed, and can be noisy. A skip only happens when
+        /// a button goes from below this value to above it. The default is `0.5`.
+        ///
+        /// Has no effect with `.hold_to_skip(...)`, which uses the press
+        /// thresholds configured in Bevy's
+        /// [`GamepadSettings`][bevy::input::gamepad::GamepadSettings].
+        pub fn gamepad_threshold(mut self, threshold: f32) -> Self {
+            self.options.gamepad_threshold = threshold;
+            self
+        }
+
+        /// Set whether touchscreen input can skip the splash screen
+        pub fn skip_touch(mut self, enable: bool) -> Self {
+            self.options.skip_touch = enable;
+            self
+        }
+
+        /// Skip the splash screen on frames when the closure returns `true`
+        ///
+        /// Use this to skip with your own input handling, such as an action
+        /// from an input mapping crate, instead of raw device input. The
+        /// closure is called every frame, before all other splash screen
+        /// systems, with read-only [`World`] access. It should only return
+        /// `true` on the frame of the press (like a "just pressed" check),
+        /// as every frame it does counts as a separate skip, which matters
+        /// with [`SkipMode::Item`].
+        ///
+        /// When set, the built-in skip input is not read at all: the
+        /// per-source settings (`skip_keyboard`, `skip_mouse`, `skip_gamepad`,
+        /// `skip_touch`, and `skip_keys`), `hold_to_skip`, and `confirm_skip`
+        /// have no effect. `.skippable(false)` still disables skipping, and
+        /// `skip_after`, `min_duration`, and `skip_mode` still apply. Calling
+        /// this again replaces the previous closure.
+        pub fn skip_when(mut self, condition: impl Fn(&World) -> bool + Send + Sync + 'static) -> Self {
+            self.options.skip_when = Some(Arc::new(condition));
+            self
+        }
+
+        /// Require the user to hold down a key/button for this long, to skip
+        ///
+        /// The hold is reset whenever everything is released. It is measured
+        /// in splash screen time, so the `clock` setting and [`SplashTimeScale`]
+        /// apply. The progress of the hold is available in the
+        /// [`SplashSkipHold`] resource.
+        pub fn hold_to_skip(mut self, duration: Duration) -> Self {
+            self.options.hold_to_skip = Some(duration);
+            self
+        }
+
+        /// Require the user to press twice, within `window` of each other, to skip
+        ///
+        /// Avoids accidental skips (such as on touchscreens). The first press
+        /// is recorded in the [`SplashSkipConfirm`] resource, which you can
+        /// use to display a "tap again to skip" hint. If no second press comes
+        /// within `window`, it is forgotten, and the next press counts as the
+        /// first again.
+        ///
+        /// Has no effect with `.hold_to_skip(...)`.
+        pub fn confirm_skip(mut self, window: Duration) -> Self {
+            self.options.confirm_skip = Some(window);
+            self
+        }
+
+        /// Set what gets skipped, when the user skips
+        ///
+        /// See [`SkipMode`].
+        pub fn skip_mode(mut self, mode: SkipMode) -> Self {
+            self.options.skip_mode = mode;
+            self
+        }
+
+        /// Set which clock the splash screen runs on
+        ///
+        /// See [`SplashClock`]. The default is [`SplashClock::Real`].
+        pub fn clock(mut self, clock: SplashClock) -> Self {
+            self.options.clock = clock;
+            self
+        }
+
+        /// Set what happens when the window loses focus
+        ///
+        /// See [`SplashFocusLoss`]. The default is [`SplashFocusLoss::Ignore`].
+        pub fn on_focus_loss(mut self, mode: SplashFocusLoss) -> Self {
+            self.options.focus_loss = mode;
+            self
+        }
+
+        /// Fill the background with a solid color during the splash screen
+        ///
+        /// This is set as the clear color of the splash screen camera,
+        /// so your [`ClearColor`] resource is left untouched.
+        pub fn background(mut self, color: Color) -> Self {
+            self.options.background = Some(color);
+            self
+        }
+
+        /// Set whether the splash screen camera clears the screen
+        ///
+        /// Set to `false` to display the splash screen as an overlay, on top
+        /// of whatever your other cameras have rendered: the camera gets
+        /// `ClearColorConfig::None` (so `background` has no effect) and a
+        /// `priority` of `1`, so that it renders after your cameras with the
+        /// default priority of `0`. The default is `true`.
+        pub fn clear(mut self, clear: bool) -> Self {
+            self.options.clear = clear;
+            self
+        }
+
+        /// Set whether to spawn a camera for the splash screen
+        ///
+        /// By default, a 2D camera is spawned when the splash screen starts,
+        /// and despawned (as it has [`SplashCleanup`]) when it ends.
+        ///
+        /// Disable this if your app already has a camera you want to use.
+        /// Your camera will be left alone (unless you insert [`SplashCleanup`]
+        /// on it), and the `background` option will not have any effect.
+        pub fn spawn_camera(mut self, spawn_camera: bool) -> Self {
+            self.options.spawn_camera = spawn_camera;
+            self
+        }
+
+        /// Only render the given layers with the spawned splash screen camera
+        ///
+        /// Useful if you composite your splash screen using multiple cameras.
+        pub fn camera_layers(mut self, layers: RenderLayers) -> Self {
+            self.options.camera_layers = Some(layers);
+            self
+        }
+
+        /// Set how the spawned splash screen camera maps the window to world units
+        ///
+        /// By default, one world unit is one logical window pixel, so logos
+        /// appear bigger or smaller depending on the resolution. For example,
+        /// with [`ScalingMode::FixedVertical`]`(1080.0)`, the window always
+        /// shows 1080 world units vertically, so a 540 units tall logo always
+        /// covers half of its height, whatever the size of the window.
+        ///
+        /// [`SplashItemFit`] and [`SplashAnchor`] measure the window in
+        /// logical pixels, so they do not line up with a different scaling.
+        /// You do not need them for consistent sizing with a fixed scaling
+        /// (you may still need to account for the aspect ratio).
+        pub fn camera_scaling(mut self, scaling: ScalingMode) -> Self {
+            self.options.camera_scaling = Some(scaling);
+            self
+        }
+
+        /// Spawn your own cameras for the splash screen
+        ///
+        /// The closure is called every time the splash screen starts, instead
+        /// of spawning the default camera (so the `background`,
+        /// `camera_layers`, and `camera_scaling` options do not have any
+        /// effect). Insert [`SplashCleanup`] on every camera you spawn, for it
+        /// to be despawned when the splash screen ends.
+        pub fn camera_setup(mut self, setup: impl Fn(&mut Commands) + Send + Sync + 'static) -> Self {
+            self.options.camera_setup = Some(Arc::new(setup));
+            self
+        }
+
+        /// Run something when the splash screen ends
+        ///
+        /// The closure is called with exclusive [`World`] access, every time
+        /// the splash screen state is exited, after all other exit systems
+        /// (and their commands), but before the splash screen entities are
+        /// despawned, and its resources are removed (see [`SplashCleanupSet`]).
+        /// Use it to, for example, start loading the next scene.
+        ///
+        /// Can be called multiple times, to add more closures.
+        /// They are run in the order they were added.
+        pub fn on_complete(mut self, hook: impl Fn(&mut World) + Send + Sync + 'static) -> Self {
+            self.options.on_complete.push(Arc::new(hook));
+            self
+        }
+
+        /// Notify the given [`SplashSignal`] whenever the splash screen ends
+        ///
+        /// Lets you observe the splash screen from outside the ECS, such as
+        /// from async tasks. Keep a clone of the signal for yourself.
+        pub fn signal(mut self, signal: SplashSignal) -> Self {
+            self.options.signal = Some(signal);
+            self
+        }
+
+        /// Fade the whole screen in when the splash screen starts, and out when it ends
+        ///
+        /// When the splash screen starts, a full-screen UI node of the color
+        /// of the [`SplashScreenFade`] is spawned on top of everything, and
+        /// faded out over `fade_in`, revealing the splash screen. When it ends,
+        /// the screen fades out over `fade_out`, just like with `.exit_fade(...)`
+        /// (which takes precedence, if you set both). A zero duration disables
+        /// either half.
+        ///
+        /// This happens on top of the fades of your splash items, which play
+        /// as usual: the fade-in does not delay them, and is not a splash item
+        /// itself, so it does not hold up the end of the splash screen. If the
+        /// splash screen ends (or is skipped) while still fading in, the fade
+        /// out starts from the current opacity, instead of jumping.
+        pub fn screen_fade(mut self, fade: SplashScreenFade) -> Self {
+            self.options.screen_fade = Some(fade);
+            self
+        }
+
+        /// Wait for the user to click, tap, or press a key, before starting
+        ///
+        /// Web browsers do not let a page play any sound until the user has
+        /// interacted with it, so on WASM, sounds in the splash screen (such
+        /// as `SplashItemAudio`) would be silent, or be delayed and play out
+        /// of sync. With this enabled, the splash screen is held at its very
+        /// start, until the first keyboard key, mouse button, or touch press.
+        /// That press unlocks audio, and then the splash screen plays from
+        /// the beginning. It does not count as a skip.
+        ///
+        /// While waiting, the [`SplashAwaitingInteraction`] resource exists:
+        /// use it to display a "click to start" prompt. Splash screen time
+        /// does not advance, so your items stay as on their first frame, no
+        /// sound is started, and nothing can end the splash screen. Gamepad
+        /// buttons are not accepted, as browsers do not count them as
+        /// interaction.
+        ///
+        /// Disabled by default.
+        pub fn require_interaction(mut self, enable: bool) -> Self {
+            self.options.require_interaction = enable;
+            self
+        }
+
+        /// Fade out the music in [`SplashMusic`], when the splash screen ends
+        ///
+        /// Instead of being cut off, the music's volume is lowered to zero over
+        /// `duration`, and then it is stopped. The fade starts as the splash
+        /// screen ends (for any reason), or as its state is exited, if that
+        /// happens first. Use the same duration as your [`SplashExitFade`],
+        /// for the sound to fade out together with the picture.
+        ///
+        /// The fade keeps going after the splash screen state has been exited,
+        /// so even if the transition is immediate (such as when skipping
+        /// without an exit fade), the music still fades out, into the next
+        /// state. Keep `duration` short, if that would be distracting.
+        ///
+        /// Requires the `audio` cargo feature.
+        #[cfg(feature = "audio")]
+        pub fn music_fade_out(mut self, duration: Duration) -> Self {
+            self.options.music_fade_out = Some(duration);
+            self
+        }
+
+        /// Ignore skip input until the splash screen has run for this long
+        ///
+        /// Useful to avoid accidental skips right as the splash screen starts.
+        pub fn skip_after(mut self, duration: Duration) -> Self {
+            self.options.skip_after = duration;
+            self
+        }
+
+        /// Ignore skip input until the first splash item has started to appear
+        ///
+        /// If enabled, skipping only works once any item with a
+        /// [`SplashItemFade`] has started its intro phase, or any item with
+        /// a [`SplashItemBlink`] has become at least partly visible, so that
+        /// a skip during the initial `wait` (while the screen is still empty)
+        /// does nothing. If none of your items have either, this has no
+        /// effect. The default is `false`.
+        pub fn skip_once_visible(mut self, enable: bool) -> Self {
+            self.options.skip_once_visible = enable;
+            self
+        }
+
+        /// Do not let the splash screen be skipped before it has run for this long
+        ///
+        /// Unlike `skip_after`, skip input is not ignored: if the user skips
+        /// too early, the skip is remembered and happens as soon as this much
+        /// time has passed.
+        pub fn min_duration(mut self, duration: Duration) -> Self {
+            self.options.min_duration = duration;
+            self
+        }
+
+        /// Only let the given keyboard keys skip the splash screen
+        ///
+        /// By default, any key can be used.
+        pub fn skip_keys(mut self, keys: impl IntoIterator<Item = KeyCode>) -> Self {
+            self.options.skip_keys = Some(keys.into_iter().collect());
+            self
+        }
+    };
 }
 
 impl<S: StateData> SplashPlugin<S> {
     /// Create a new splash screen
     ///
-    /// Will run in `state` and transition to `next`.
+    /// Will run in `state` and transition to `next`. They should be
+    /// different states (an error is logged if they are not).
     pub fn new(state: S, next: S) -> Self {
+        SplashPlugin {
+            next: Some(next),
+            ..Self::manual(state)
+        }
+    }
+
+    /// Create a new splash screen, without a state to transition to
+    ///
+    /// Will run in `state`, and stay in it after it has ended, until you
+    /// transition to another state yourself (such as when you receive
+    /// the [`SplashCompleted`] event).
+    pub fn manual(state: S) -> Self {
         SplashPlugin {
             state,
-            next,
-            skippable: true,
+            next: None,
+            options: SplashOptions::default(),
+            return_to_previous: false,
+            max_duration: None,
+            assets: Vec::new(),
+            conditions: Vec::new(),
+            skip_waits_for_condition: false,
+        }
+    }
+
+    splash_builder_methods!();
+
+    /// Transition back to the state that was active before the splash screen
+    ///
+    /// Useful for splash screens shown as interstitials, that can be
+    /// entered from anywhere. If there was no previous state (such as if
+    /// the app started in the splash screen state), `next` is used instead
+    /// (if there is one).
+    ///
+    /// Disabled by default.
+    pub fn return_to_previous(mut self, enable: bool) -> Self {
+        self.return_to_previous = enable;
+        self
+    }
+
+    /// Fade the screen out, before leaving the splash screen
+    ///
+    /// When the splash screen ends (for any reason), a full-screen UI node
+    /// is spawned on top of everything, and faded in to the color of the
+    /// [`SplashExitFade`]. The state transition only happens once it is
+    /// fully opaque. [`SplashCompleted`] is still sent when the splash
+    /// screen ends, as the fade starts.
+    ///
+    /// The splash screen has already ended by then, so input during the
+    /// fade (including skipping again) is ignored, and the fade always
+    /// plays to the end. It runs on the splash screen clock, so it is
+    /// paused by [`SplashPaused`].
+    pub fn exit_fade(mut self, fade: SplashExitFade) -> Self {
+        self.options.exit_fade = Some(fade);
+        self
+    }
+
+    /// Run the splash screen's per-frame systems in this stage
+    ///
+    /// By default, they run in [`CoreStage::Update`]. Use this to run
+    /// them in your own stage instead, such as one with a fixed timestep.
+    /// All of them go in the same stage, so [`SplashSet`] still orders
+    /// them (and your own systems in that stage) as usual.
+    ///
+    /// The stage should run before [`CoreStage::PostUpdate`], every frame:
+    /// otherwise the changes made to items' transforms are only
+    /// propagated (and rendered) on the next frame. The splash screen
+    /// keeps its own time (see the `clock` setting), so it plays at the
+    /// same speed even if the stage does not run every frame, but it only
+    /// changes on frames when it does.
+    ///
+    /// With `iyes_loopless`, enter and exit systems are not affected: they
+    /// run in your state's transition stage. Bevy's own [`State`] only
+    /// works in the stage it was added to, so the enter and exit systems
+    /// go in this stage too, and you must add the state to it (with
+    /// [`App::add_state_to_stage`]).
+    pub fn stage(mut self, stage: impl StageLabel) -> Self {
+        self.options.stage = stage.as_label();
+        self
+    }
+
+    /// Force the splash screen to end after it has run for this long
+    ///
+    /// A safety valve, in case the splash screen would otherwise never
+    /// end (for example, if none of its items ever finish). A warning is
+    /// logged when this happens, as it usually indicates a bug. The splash
+    /// screen ends with [`SplashEndReason::TimedOut`].
+    pub fn max_duration(mut self, duration: Duration) -> Self {
+        self.max_duration = Some(duration);
+        self
+    }
+
+    /// Do not end the splash screen until these assets have loaded
+    ///
+    /// The splash screen keeps running (even if all items have finished,
+    /// or the user has skipped it) until every asset has either loaded or
+    /// failed to load. Failures are logged as errors, but do not prevent
+    /// the splash screen from ending. The handles are kept alive for as
+    /// long as the splash screen runs.
+    ///
+    /// The handles must come from the [`AssetServer`]. To wait for assets
+    /// you start loading once the splash screen is running, use
+    /// [`SplashCommandsExt::wait_for_splash_assets`] instead.
+    pub fn wait_for_assets(mut self, handles: impl IntoIterator<Item = HandleUntyped>) -> Self {
+        self.assets.extend(handles);
+        self
+    }
+
+    /// Do not complete the splash screen until the closure returns `true`
+    ///
+    /// Useful if the splash screen should last until something outside
+    /// of it is ready, such as a network handshake. The closure is called
+    /// every frame with read-only [`World`] access, for example to check a
+    /// flag in one of your resources. The splash screen completes once all
+    /// items have finished *and* the closure returns `true`.
+    ///
+    /// Can be called multiple times, to add more closures. All of them
+    /// must return `true`.
+    ///
+    /// Skipping still ends the splash screen immediately, unless you
+    /// enable `.skip_waits_for_condition(true)`. `min_duration` only
+    /// delays skips, so it does not interact with this: if a skip has to
+    /// wait for both, it happens once both allow it. `max_duration` still
+    /// forces the splash screen to end, even if the closure never returns
+    /// `true`.
+    pub fn wait_until(mut self, condition: impl Fn(&World) -> bool + Send + Sync + 'static) -> Self {
+        self.conditions.push(Arc::new(condition));
+        self
+    }
+
+    /// Set whether skipping also waits for the `.wait_until(...)` closures
+    ///
+    /// If enabled, a skip is remembered (like with `min_duration`), and
+    /// happens as soon as all closures return `true`. Disabled by default.
+    pub fn skip_waits_for_condition(mut self, enable: bool) -> Self {
+        self.skip_waits_for_condition = enable;
+        self
+    }
+
+    fn config(&self) -> SplashConfig {
+        let config = self.options.config();
+        SplashConfig {
+            max_duration: self.max_duration,
+            assets: self.assets.clone(),
+            conditions: self.conditions.iter()
+                .cloned()
+                .chain(config.conditions.iter().cloned())
+                .collect(),
+            skip_waits_for_condition: self.skip_waits_for_condition,
+            ..config
         }
     }
 }
@@ -75,18 +737,20 @@ impl<S: StateData> SplashPlugin<S> {
 /// as loading assets, etc.) during your splash screen.
 //
 /// The plugin will report the state of the splash screen
-/// as "hidden progress" to `iyes_progress`, so it can be
-/// accounted together with your other progress-tracking
-/// systems. The splash screen is considered "completed"
-/// when either all items have timed out, or the user has
-/// chosen to skip it.
+/// as progress to `iyes_progress`, so it can be accounted
+/// together with your other progress-tracking systems (see
+/// `.progress_visibility(...)`). The splash screen is
+/// considered "completed" when either all items have timed
+/// out, or the user has chosen to skip it.
 ///
 /// In effect, if the user chooses to skip the splash screen,
 /// but there is still other incomplete work going on, the
 /// skip will be delayed until your backgound work completes.
 ///
-/// This plugin will never trigger a state transition by
-/// itself. Configure your next state in `iyes_progress`.
+/// By default, this plugin will never trigger a state transition
+/// by itself. Configure your next state in `iyes_progress`, or
+/// use `.next(...)` to have this plugin transition once all
+/// progress (including the splash screen) has completed.
 ///
 /// ---
 ///
@@ -95,6 +759,13 @@ impl<S: StateData> SplashPlugin<S> {
 /// entities and insert one of the following components:
 ///  - [`SplashItemTimeout`]
 ///  - [`SplashItemFade`]
+///  - [`SplashItemScale`]
+///  - [`SplashItemMove`]
+///  - [`SplashItemBlink`]
+///  - `SplashItemAudio` (with the `audio` cargo feature)
+///
+/// Also insert [`SplashCleanup`] on them, so they get
+/// despawned when the splash screen ends.
 ///
 /// When the [`Timer`][bevy::time::Timer]s inside all
 /// such components have completed (all splash screen
@@ -104,15 +775,47 @@ impl<S: StateData> SplashPlugin<S> {
 /// The splash screen is skippable by the user, by default.
 /// Any of the following input events will cause the
 /// splash screen to "complete" immediately:
-///  - any keyboard keypress
+///  - any keyboard keypress (or only some keys, see `.skip_keys(...)`)
 ///  - any mouse button press
 ///  - any gamepad button press
 ///  - any started touchscreen touch
 ///
-/// To disable this behavior, set `skippable` to `false`.
+/// Input is ignored until at least one splash item exists,
+/// so that nothing is skipped if you spawn your items late.
+///
+/// A [`SplashSkipped`] event is sent when that happens.
+/// Either way, a [`SplashCompleted`] event is sent when
+/// the splash screen ends, telling you the reason.
+///
+/// The input that skipped the splash screen is consumed when
+/// it ends, so it does not also get handled by the next state:
+/// pending input events are dropped, and [`Input`] will not
+/// report anything as "just pressed".
+///
+/// To disable this behavior, use `.skippable(false)`.
+/// You can also disable individual input sources, using
+/// `.skip_keyboard(false)`, `.skip_mouse(false)`, etc.
+/// To only disable it for a short while after the splash
+/// screen starts, use `.skip_after(duration)`, or
+/// `.skip_once_visible(true)` to wait until something is shown.
+///
+/// To show the overall loading progress to the user, insert
+/// `SplashProgressText` on a text entity (with the `iyes_progress`
+/// cargo feature).
+///
+/// Like with [`SplashPlugin`], `state` is an `iyes_loopless`
+/// state by default, or Bevy's own [`State`] if the default
+/// `iyes_loopless` cargo feature is disabled.
 pub struct SplashProgressPlugin<S: StateData> {
-    pub state: S,
-    pub skippable: bool,
+    // (only used by the `Plugin` impl, which needs `iyes_progress`)
+    #[cfg_attr(not(feature = "iyes_progress"), allow(dead_code))]
+    state: S,
+    next: Option<S>,
+    options: SplashOptions,
+    progress_weight: u32,
+    skip_min_progress: f32,
+    #[cfg(feature = "iyes_progress")]
+    progress_visibility: ProgressVisibility,
 }
 
 impl<S: StateData> SplashProgressPlugin<S> {
@@ -123,180 +826,4848 @@ impl<S: StateData> SplashProgressPlugin<S> {
     pub fn new(state: S) -> Self {
         SplashProgressPlugin {
             state,
-            skippable: true,
+            next: None,
+            options: SplashOptions::default(),
+            progress_weight: 100,
+            skip_min_progress: 0.0,
+            #[cfg(feature = "iyes_progress")]
+            progress_visibility: ProgressVisibility::Visible,
+        }
+    }
+
+    splash_builder_methods!();
+
+    /// Set how many units of progress the splash screen counts as
+    ///
+    /// Every frame, the splash screen reports `Progress { done, total }` to
+    /// `iyes_progress`, with `total` set to this weight. `done` is the
+    /// average progress of all splash items (how much of their timers has
+    /// elapsed), scaled to the weight. When the splash screen is completed
+    /// or skipped, `done` jumps to the full weight.
+    ///
+    /// Increase this if you track many units of other work (such as lots
+    /// of assets), so that the splash screen is not dwarfed by it in the
+    /// overall progress. The default is `100`. Zero is treated as `1`.
+    /// Has no effect with `ProgressVisibility::Hidden`.
+    pub fn progress_weight(mut self, weight: u32) -> Self {
+        self.progress_weight = weight;
+        self
+    }
+
+    /// Do not let the splash screen be skipped before enough of your other work is done
+    ///
+    /// `fraction` is in the `0.0..=1.0` range, of the combined progress
+    /// of all your other progress-tracking systems (not counting the
+    /// splash screen itself). Like with `min_duration`, skip input is not
+    /// ignored: if the user skips too early, the skip is remembered and
+    /// happens as soon as enough progress has been made. This way, the
+    /// user can skip the splash screen, but not into a state that is not
+    /// ready enough, even if you do not hold up the state transition until
+    /// all of your work is done.
+    ///
+    /// Progress is measured at the end of every frame, so the skip
+    /// happens on the frame after the threshold was reached. If nothing
+    /// else tracks progress, it counts as all done. The default is `0.0`.
+    pub fn skip_min_progress(mut self, fraction: f32) -> Self {
+        self.skip_min_progress = fraction;
+        self
+    }
+
+    /// Set how the splash screen shows up in the overall progress
+    ///
+    /// See [`ProgressVisibility`]. The default is `Visible`.
+    #[cfg(feature = "iyes_progress")]
+    pub fn progress_visibility(mut self, visibility: ProgressVisibility) -> Self {
+        self.progress_visibility = visibility;
+        self
+    }
+
+    /// Transition to `next` when all progress has completed
+    ///
+    /// This is checked at the end of every frame, after all progress
+    /// tracking systems have run, so it accounts for your other work too,
+    /// not just the splash screen. You still need to add `iyes_progress`'s
+    /// `ProgressPlugin` for the state, but without `.continue_to(...)`.
+    pub fn next(mut self, next: S) -> Self {
+        self.next = Some(next);
+        self
+    }
+
+    /// Fade the screen out, before leaving the splash screen
+    ///
+    /// When the splash screen ends (for any reason), a full-screen UI node
+    /// is spawned on top of everything, and faded in to the color of the
+    /// [`SplashExitFade`]. The state transition can only happen once it is
+    /// fully opaque: the splash screen only reports full progress to
+    /// `iyes_progress` once the fade has finished. [`SplashCompleted`] is
+    /// still sent when the splash screen ends, as the fade starts.
+    ///
+    /// The splash screen has already ended by then, so input during the
+    /// fade (including skipping again) is ignored, and the fade always
+    /// plays to the end. It runs on the splash screen clock, so it is
+    /// paused by [`SplashPaused`].
+    pub fn exit_fade(mut self, fade: SplashExitFade) -> Self {
+        self.options.exit_fade = Some(fade);
+        self
+    }
+
+    /// Run the splash screen's per-frame systems in this stage
+    ///
+    /// By default, they run in [`CoreStage::Update`]. Use this to run
+    /// them in your own stage instead, such as one with a fixed timestep.
+    /// All of them go in the same stage, so [`SplashSet`] still orders
+    /// them (and your own systems in that stage) as usual.
+    ///
+    /// The stage should run before [`CoreStage::PostUpdate`], every frame:
+    /// otherwise the changes made to items' transforms are only
+    /// propagated (and rendered) on the next frame. The splash screen
+    /// keeps its own time (see the `clock` setting), so it plays at the
+    /// same speed even if the stage does not run every frame, but it only
+    /// changes on frames when it does.
+    ///
+    /// With `iyes_loopless`, enter and exit systems are not affected: they
+    /// run in your state's transition stage. Bevy's own [`State`] only
+    /// works in the stage it was added to, so the enter and exit systems
+    /// go in this stage too, and you must add the state to it (with
+    /// [`App::add_state_to_stage`]). Also, without `iyes_loopless`,
+    /// `iyes_progress` only tracks progress in [`CoreStage::Update`].
+    pub fn stage(mut self, stage: impl StageLabel) -> Self {
+        self.options.stage = stage.as_label();
+        self
+    }
+
+    #[cfg(feature = "iyes_progress")]
+    fn config(&self) -> SplashConfig {
+        self.options.config()
+    }
+}
+
+impl<S: StateData> Plugin for SplashPlugin<S> {
+    fn build(&self, app: &mut App) {
+        let stage = self.options.stage;
+        if self.next.as_ref() == Some(&self.state) {
+            error!(
+                "SplashPlugin is configured to transition from {:?} to itself! \
+                 The splash screen will restart forever, every time it ends.",
+                self.state
+            );
+        }
+        if !add_splash_systems(app, self.state.clone(), self.config()) {
+            return;
+        }
+        // other splash screens may use other values of the same state type
+        let mut next = app.world.get_resource_or_insert_with(|| SplashNext::<S>(HashMap::new()));
+        if let Some(state) = &self.next {
+            next.0.insert(self.state.clone(), state.clone());
         }
+        if self.return_to_previous {
+            // multiple splash screens may share the same state type
+            if !app.world.contains_resource::<SplashLastState<S>>() {
+                app.insert_resource(SplashLastState::<S>(None));
+                app.add_system_to_stage(CoreStage::First, splash_track_state::<S>);
+            }
+            on_enter(app, stage, self.state.clone(), splash_return_init(self.state.clone()));
+        }
+        add_system_set(
+            app,
+            stage,
+            on_update(self.state.clone())
+                .label(SplashSet::Complete)
+                .after(SplashLabel::Skip)
+                .after(SplashLabel::Items)
+                .after(SplashLabel::Colors)
+                .with_system(splash_complete::<S>)
+        );
     }
 }
-#[cfg(feature = "iyes_loopless")]
-impl Plugin for SplashPlugin {
+
+#[cfg(feature = "iyes_progress")]
+impl<S: StateData> Plugin for SplashProgressPlugin<S> {
     fn build(&self, app: &mut App) {
-        app.add_enter_system(AppGlobalState::SplashIyes, splash_init_iyes);
-        app.add_exit_system(AppGlobalState::SplashIyes, despawn_with_recursive::<SplashCleanup>);
-        app.add_exit_system(AppGlobalState::SplashIyes, remove_resource::<SplashNext>);
-        app.add_enter_system(AppGlobalState::SplashBevy, splash_init_bevy);
-        app.add_exit_system(AppGlobalState::SplashBevy, despawn_with_recursive::<SplashCleanup>);
-        app.add_exit_system(AppGlobalState::SplashBevy, remove_resource::<SplashNext>);
-        app.add_system_set(
-            ConditionSet::new()
-                .run_in_state(AppGlobalState::SplashIyes)
-                .with_system(splash_skip)
-                .with_system(splash_fade)
-                .into()
+        use iyes_progress::prelude::*;
+
+        let stage = self.options.stage;
+        if !add_splash_systems(app, self.state.clone(), self.config()) {
+            return;
+        }
+        on_enter(app, stage, self.state.clone(), insert_resource(SplashProgressConfig {
+            weight: self.progress_weight.max(1),
+            visibility: self.progress_visibility,
+        }));
+        on_cleanup(app, stage, self.state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashProgressConfig>);
+        if self.skip_min_progress > 0.0 {
+            on_enter(app, stage, self.state.clone(), insert_resource(SplashLoadProgress {
+                min: self.skip_min_progress,
+                ..Default::default()
+            }));
+            on_cleanup(app, stage, self.state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashLoadProgress>);
+            add_after_progress(app, stage, self.state.clone(), splash_load_progress);
+        }
+        add_system_set(
+            app,
+            stage,
+            on_update(self.state.clone())
+                .label(SplashSet::Complete)
+                .after(SplashLabel::Skip)
+                .after(SplashLabel::Items)
+                .after(SplashLabel::Colors)
+                .with_system(splash_progress::<S>.track_progress())
         );
-        app.add_system_set(
-            ConditionSet::new()
-                .run_in_state(AppGlobalState::SplashBevy)
-                .with_system(splash_skip)
-                .with_system(splash_fade)
-                .into()
+        add_system_set(
+            app,
+            stage,
+            on_update(self.state.clone())
+                .label(SplashSet::Complete)
+                .with_system(splash_progress_text)
         );
-        app.add_exit_system(AppGlobalState::SplashBevy, remove_resource::<Splashes>);
-        app.add_system_to_stage(CoreStage::PostUpdate, update_loading_pct.run_in_state(AppGlobalState::AssetsLoading));
+        if let Some(next) = &self.next {
+            add_after_progress(app, stage, self.state.clone(), splash_progress_transition(next.clone()));
+        }
     }
 }
 
-// fn update_loading_pct(
-//     mut q: Query<&mut Text, With<LoadingPctText>>,
-//     progress: Res<ProgressCounter>,
-// ) {
-//     let progress: f32 = progress.progress().into();
-//     for mut txt in q.iter_mut() {
-//         txt.sections[0].value = format!("{:.0}%", progress * 100.0);
-//     }
-// }
+/// Transition to `next` once all `iyes_progress` progress is ready
+#[cfg(feature = "iyes_progress")]
+fn splash_progress_transition<S: StateData>(next: S) -> impl FnMut(&mut World) {
+    use bevy::ecs::system::Command;
 
-#[derive(Component)]
-struct SplashCleanup;
+    move |world| {
+        let ready = world
+            .get_resource::<iyes_progress::ProgressCounter>()
+            .map(|counter| counter.progress())
+            .is_some_and(|progress| progress.total > 0 && progress.done >= progress.total);
+        if ready {
+            let span = world.get_resource::<SplashConfig>().map(|config| config.span.clone());
+            let _span = span.as_ref().map(|span| span.enter());
+            info!(next = ?next, "Leaving the splash screen, as all progress is ready");
+            SplashTransition(next.clone()).write(world);
+        }
+    }
+}
 
-struct SplashNext(AppGlobalState);
+/// Record the progress of all other work, for `skip_min_progress`
+#[cfg(feature = "iyes_progress")]
+fn splash_load_progress(world: &mut World) {
+    let progress = match world.get_resource::<iyes_progress::ProgressCounter>() {
+        Some(counter) => counter.progress(),
+        None => return,
+    };
+    if let Some(mut load) = world.get_resource_mut::<SplashLoadProgress>() {
+        let done = progress.done.saturating_sub(load.own.0);
+        let total = progress.total.saturating_sub(load.own.1);
+        load.fraction = if total == 0 { 1.0 } else { done as f32 / total as f32 };
+    }
+}
 
-fn splash_init_iyes(
-    mut commands: Commands,
-    splashes: Res<Splashes>,
-) {
-    commands.insert_resource(SplashNext(AppGlobalState::SplashBevy));
-    commands.spawn_bundle(Camera2dBundle::default())
-        .insert(SplashCleanup);
-    commands.spawn_bundle(SpriteBundle {
-        texture: splashes.logo_iyeshead.clone(),
-        transform: Transform::from_xyz(0.0, 75.0, 0.0),
-        ..Default::default()
-    }).insert(SplashCleanup)
-    .insert(SplashFade::new(0.0, 0.0, 1.25, 1.5));
-    commands.spawn_bundle(SpriteBundle {
-        texture: splashes.logo_iyestext.clone(),
-        transform: Transform::from_xyz(0.0, -175.0, 0.0),
-        ..Default::default()
-    }).insert(SplashCleanup)
-    .insert(SplashFade::new(0.25, 0.75, 0.25, 1.75));
+/// Add an exclusive system, after all progress tracking systems
+///
+/// Mirrors where `iyes_progress` checks progress for the same backend.
+#[cfg(all(feature = "iyes_progress", feature = "iyes_loopless"))]
+fn add_after_progress<S: StateData>(app: &mut App, _stage: StageLabelId, state: S, system: impl FnMut(&mut World) + Send + Sync + 'static) {
+    use iyes_loopless::condition::IntoConditionalExclusiveSystem;
+
+    app.add_system_to_stage(
+        CoreStage::Last,
+        system
+            .run_in_state(state)
+            .at_end(),
+    );
 }
 
-fn splash_init_bevy(
-    mut commands: Commands,
-    splashes: Res<Splashes>,
-) {
-    commands.insert_resource(SplashNext(AppGlobalState::MainMenu));
-    commands.spawn_bundle(Camera2dBundle::default())
-        .insert(SplashCleanup);
-    commands.spawn_bundle(SpriteBundle {
-        texture: splashes.logo_bevy.clone(),
-        transform: Transform::from_xyz(0.0, 0.0, 0.0),
-        ..Default::default()
-    }).insert(SplashCleanup)
-    .insert(SplashFade::new(0.0, 0.5, 1.0, 1.5));
+/// Add an exclusive system, after all progress tracking systems
+///
+/// Mirrors where `iyes_progress` checks progress for the same backend.
+#[cfg(all(feature = "iyes_progress", not(feature = "iyes_loopless")))]
+fn add_after_progress<S: StateData>(app: &mut App, stage: StageLabelId, state: S, system: impl FnMut(&mut World) + Send + Sync + 'static) {
+    app.add_system_set_to_stage(
+        stage,
+        SystemSet::on_update(state)
+            .with_system(system.exclusive_system().at_end())
+    );
 }
 
-#[derive(Component)]
-struct SplashFade {
-    timer_wait: Timer,
-    timer_intro: Timer,
-    timer_on: Timer,
-    timer_fade: Timer,
+/// Add an exclusive system, before all other splash screen systems
+#[cfg(feature = "iyes_loopless")]
+fn add_at_start<S: StateData>(app: &mut App, stage: StageLabelId, state: S, system: impl FnMut(&mut World) + Send + Sync + 'static) {
+    use iyes_loopless::condition::IntoConditionalExclusiveSystem;
+
+    app.add_system_to_stage(
+        stage,
+        system
+            .run_in_state(state)
+            .at_start(),
+    );
 }
 
-impl SplashFade {
-    fn new(wait: f32, intro: f32, on: f32, fade: f32) -> Self {
-        Self {
-            timer_wait: Timer::from_seconds(wait, false),
-            timer_intro: Timer::from_seconds(intro, false),
-            timer_on: Timer::from_seconds(on, false),
-            timer_fade: Timer::from_seconds(fade, false),
-        }
-    }
+/// Add an exclusive system, before all other splash screen systems
+#[cfg(not(feature = "iyes_loopless"))]
+fn add_at_start<S: StateData>(app: &mut App, stage: StageLabelId, state: S, system: impl FnMut(&mut World) + Send + Sync + 'static) {
+    app.add_system_set_to_stage(
+        stage,
+        SystemSet::on_update(state)
+            .with_system(system.exclusive_system().at_start())
+    );
 }
 
-fn splash_fade(
-    mut q: Query<(&mut Sprite, &mut SplashFade)>,
-    mut commands: Commands,
-    t: Res<Time>,
-    next: Res<SplashNext>,
-) {
-    let mut all_finished = true;
-    let mut count = 0;
-    for (mut sprite, mut fade) in q.iter_mut() {
-        count += 1;
-        if fade.timer_wait.duration().as_secs_f32() > 0.0 && !fade.timer_wait.finished() {
-            fade.timer_wait.tick(t.delta());
-            all_finished = false;
-            sprite.color.set_a(0.0);
-        } else if fade.timer_intro.duration().as_secs_f32() > 0.0 && !fade.timer_intro.finished() {
-            fade.timer_intro.tick(t.delta());
-            all_finished = false;
-            let remain = fade.timer_intro.percent();
-            sprite.color.set_a(remain);
-        } else if !fade.timer_on.finished() {
-            fade.timer_on.tick(t.delta());
-            all_finished = false;
-            sprite.color.set_a(1.0);
-        } else if !fade.timer_fade.finished() {
-            fade.timer_fade.tick(t.delta());
-            all_finished = false;
-            let remain = fade.timer_fade.percent_left();
-            sprite.color.set_a(remain);
-        }
-    }
-    if all_finished && count > 0 {
-        commands.insert_resource(NextState(next.0));
-    }
-}
-
-fn splash_skip(
-    mut commands: Commands,
-    mut kbd: EventReader<KeyboardInput>,
-    mut mouse: EventReader<MouseButtonInput>,
-    mut gamepad: EventReader<GamepadEvent>,
-    mut touch: EventReader<TouchInput>,
+/// Add an enter system for the splash screen state
+#[cfg(feature = "iyes_loopless")]
+fn on_enter<S: StateData, P>(app: &mut App, _stage: StageLabelId, state: S, system: impl IntoSystemDescriptor<P>) {
+    app.add_enter_system(state, system);
+}
+
+/// Add an enter system for the splash screen state
+#[cfg(not(feature = "iyes_loopless"))]
+fn on_enter<S: StateData, P>(app: &mut App, stage: StageLabelId, state: S, system: impl IntoSystemDescriptor<P>) {
+    app.add_system_set_to_stage(stage, SystemSet::on_enter(state).with_system(system));
+}
+
+/// Add an exit system for the splash screen state
+#[cfg(feature = "iyes_loopless")]
+fn on_exit<S: StateData, P>(app: &mut App, _stage: StageLabelId, state: S, system: impl IntoSystemDescriptor<P>) {
+    app.add_exit_system(state, system);
+}
+
+/// Add an exit system for the splash screen state
+#[cfg(not(feature = "iyes_loopless"))]
+fn on_exit<S: StateData, P>(app: &mut App, stage: StageLabelId, state: S, system: impl IntoSystemDescriptor<P>) {
+    app.add_system_set_to_stage(stage, SystemSet::on_exit(state).with_system(system));
+}
+
+/// Set of systems that run every frame, while in the splash screen state
+#[cfg(feature = "iyes_loopless")]
+fn on_update<S: StateData>(state: S) -> ConditionSet {
+    ConditionSet::new().run_in_state(state)
+}
+
+/// Set of systems that run every frame, while in the splash screen state
+#[cfg(not(feature = "iyes_loopless"))]
+fn on_update<S: StateData>(state: S) -> SystemSet {
+    SystemSet::on_update(state)
+}
+
+/// Add a cleanup system for the splash screen state
+///
+/// Runs at the end of the exit systems, in the order of [`SplashCleanupSet`].
+fn on_cleanup<S: StateData>(
+    app: &mut App,
+    stage: StageLabelId,
+    state: S,
+    set: SplashCleanupSet,
+    system: impl FnMut(&mut World) + Send + Sync + 'static,
 ) {
-    use bevy::input::ButtonState;
-    use bevy::input::touch::TouchPhase;
+    let system = system.exclusive_system().at_end().label(set);
+    let system = match set {
+        SplashCleanupSet::Complete => system,
+        SplashCleanupSet::Despawn => system.after(SplashCleanupSet::Complete),
+        SplashCleanupSet::Resources => system.after(SplashCleanupSet::Despawn),
+    };
+    on_exit(app, stage, state, system);
+}
 
-    let mut done = false;
+/// Add a set of systems created with [`on_update`], to the splash screen's stage
+fn add_system_set(app: &mut App, stage: StageLabelId, set: impl Into<SystemSet>) {
+    app.add_system_set_to_stage(stage, set.into());
+}
 
-    for ev in kbd.iter() {
-        if let ButtonState::Pressed = ev.state {
-            done = true;
-        }
+/// Add everything common to all splash screen plugins
+///
+/// Returns `false` (and adds nothing) if there already is
+/// a splash screen plugin for the same state.
+fn add_splash_systems<S: StateData>(app: &mut App, state: S, config: SplashConfig) -> bool {
+    // (easy to get wrong, as the default feature may be enabled unknowingly)
+    #[cfg(feature = "iyes_loopless")]
+    assert!(
+        app.schedule
+            .get_stage::<StateTransitionStage<S>>(&iyes_loopless::state::StateTransitionStageLabel::from_type::<S>())
+            .is_some(),
+        "Splash screens use {} as an `iyes_loopless` state, but it has not been added! \
+         Add it with `add_loopless_state`, before the splash screen plugins. Or, to use \
+         Bevy's own `State` instead, disable the default `iyes_loopless` cargo feature.",
+        std::any::type_name::<S>()
+    );
+    let registered = app.world
+        .get_resource_or_insert_with(|| SplashStates::<S>(HashSet::new()))
+        .0
+        .insert(state.clone());
+    if !registered {
+        error!(
+            "A splash screen plugin has already been added for {:?}! Ignoring the new one.",
+            state
+        );
+        return false;
     }
-
-    for ev in mouse.iter() {
-        if let ButtonState::Pressed = ev.state {
-            done = true;
+    // for inspectors and scenes
+    app.register_type::<SplashItemFade>();
+    app.register_type::<SplashItemTimeout>();
+    app.register_type::<SplashItemRepeat>();
+    app.register_type::<SkipAllSplashes>();
+    app.register_type::<SplashPaused>();
+    app.register_type::<SplashTimeScale>();
+    app.register_type::<SplashTestClock>();
+    #[cfg(debug_assertions)]
+    if !app.world.contains_resource::<SkipAllSplashes>()
+        && std::env::var_os("IYES_SPLASH_SKIP").is_some()
+    {
+        app.insert_resource(SkipAllSplashes(true));
+    }
+    // multiple splash screens may share the same state type
+    if !app.world.contains_resource::<Events<SplashSkipped<S>>>() {
+        app.add_event::<SplashSkipped<S>>();
+    }
+    if !app.world.contains_resource::<Events<SplashCompleted<S>>>() {
+        app.add_event::<SplashCompleted<S>>();
+    }
+    if !app.world.contains_resource::<Events<SplashStarted<S>>>() {
+        app.add_event::<SplashStarted<S>>();
+    }
+    let skippable = config.skippable;
+    let hold_to_skip = config.hold_to_skip;
+    let confirm_skip = config.confirm_skip;
+    let skip_when = config.skip_when.clone();
+    let focus_loss = config.focus_loss;
+    let stage = config.stage;
+    let require_interaction = config.require_interaction;
+    #[cfg(feature = "audio")]
+    let music_fade_out = config.music_fade_out;
+    let hooks = config.on_complete.clone();
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Complete, move |world: &mut World| {
+        for hook in &hooks {
+            hook(world);
         }
+    });
+    if !config.conditions.is_empty() {
+        add_at_start(app, stage, state.clone(), splash_condition_check(config.conditions.clone()));
+        on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashWaiting>);
     }
-
-    for ev in gamepad.iter() {
-        if let GamepadEventType::ButtonChanged(_, _) = ev.event_type {
-            done = true;
+    on_enter(app, stage, state.clone(), splash_init(config, state.clone()));
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Despawn, splash_cleanup);
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashConfig>);
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashCurrent<S>>);
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashTime>);
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashState>);
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashRoot>);
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashItems>);
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashSkipRequested>);
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashEnded>);
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashAssets>);
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashExitFading>);
+    on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashScreenFading>);
+    if require_interaction {
+        on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashAwaitingInteraction>);
+        add_system_set(
+            app,
+            stage,
+            on_update(state.clone())
+                .label(SplashSet::Input)
+                .after(SplashLabel::Clock)
+                .with_system(splash_interaction)
+        );
+    }
+    add_system_set(
+        app,
+        stage,
+        on_update(state.clone())
+            .label(SplashLabel::Clock)
+            .label(SplashSet::Animate)
+            .with_system(splash_clock)
+    );
+    if focus_loss != SplashFocusLoss::Ignore {
+        on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, splash_focus_cleanup);
+        add_system_set(
+            app,
+            stage,
+            on_update(state.clone())
+                .label(SplashSet::Input)
+                .after(SplashLabel::Clock)
+                .with_system(splash_focus::<S>)
+        );
+    }
+    add_system_set(
+        app,
+        stage,
+        on_update(state.clone())
+            .label(SplashLabel::Items)
+            .label(SplashSet::Animate)
+            .after(SplashLabel::Clock)
+            .with_system(splash_fade)
+            .with_system(splash_timeout)
+            .with_system(splash_scale)
+            .with_system(splash_move)
+            .with_system(splash_blink)
+            .with_system(splash_anchor)
+            .with_system(splash_fit)
+            .with_system(splash_layer)
+            .with_system(splash_assets)
+    );
+    // both use the exit fade: the screen fade hands over to it,
+    // before it advances on the same frame
+    add_system_set(
+        app,
+        stage,
+        on_update(state.clone())
+            .label(SplashLabel::ScreenFade)
+            .label(SplashLabel::Items)
+            .label(SplashSet::Animate)
+            .after(SplashLabel::Clock)
+            .with_system(splash_screen_fade)
+    );
+    add_system_set(
+        app,
+        stage,
+        on_update(state.clone())
+            .label(SplashLabel::Items)
+            .label(SplashSet::Animate)
+            .after(SplashLabel::Clock)
+            .after(SplashLabel::ScreenFade)
+            .with_system(splash_exit_fade)
+    );
+    add_system_set(
+        app,
+        stage,
+        on_update(state.clone())
+            .label(SplashLabel::Hover)
+            .label(SplashSet::Animate)
+            .after(SplashLabel::Items)
+            .with_system(splash_hover)
+    );
+    add_system_set(
+        app,
+        stage,
+        on_update(state.clone())
+            .label(SplashLabel::Colors)
+            .label(SplashSet::Animate)
+            .after(SplashLabel::Items)
+            .after(SplashLabel::Hover)
+            .with_system(splash_fade_target::<Sprite>)
+            .with_system(splash_fade_target::<UiColor>)
+            .with_system(splash_fade_text)
+            .with_system(splash_fade_material)
+    );
+    #[cfg(feature = "audio")]
+    {
+        add_system_set(
+            app,
+            stage,
+            on_update(state.clone())
+                .label(SplashLabel::Items)
+                .label(SplashSet::Animate)
+                .after(SplashLabel::Clock)
+                .with_system(splash_audio)
+        );
+        on_exit(app, stage, state.clone(), splash_audio_stop);
+        if music_fade_out.is_some() {
+            // fades continue after the splash screen state has been exited
+            if !app.world.contains_resource::<SplashMusicFading>() {
+                app.insert_resource(SplashMusicFading(Vec::new()));
+                app.add_system_to_stage(CoreStage::Last, splash_music_fade);
+            }
+            add_system_set(
+                app,
+                stage,
+                on_update(state.clone())
+                    .after(SplashSet::Complete)
+                    .with_system(splash_music_fade_start(false))
+            );
+            on_exit(app, stage, state.clone(), splash_music_fade_start(true));
         }
     }
-
-    for ev in touch.iter() {
-        if let TouchPhase::Started = ev.phase {
-            done = true;
+    if skippable {
+        on_exit(app, stage, state.clone(), splash_consume_input);
+        if let Some(condition) = skip_when {
+            add_at_start(app, stage, state.clone(), splash_skip_trigger(condition));
+            on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashSkipTriggered>);
+            add_system_set(
+                app,
+                stage,
+                on_update(state)
+                    .label(SplashLabel::Skip)
+                    .label(SplashSet::Input)
+                    .after(SplashLabel::Clock)
+                    .with_system(splash_skip_when::<S>)
+            );
+        } else if let Some(duration) = hold_to_skip {
+            on_enter(app, stage, state.clone(), insert_resource(SplashSkipHold {
+                held: Duration::ZERO,
+                required: duration,
+            }));
+            on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashSkipHold>);
+            add_system_set(
+                app,
+                stage,
+                on_update(state)
+                    .label(SplashLabel::Skip)
+                    .label(SplashSet::Input)
+                    .after(SplashLabel::Clock)
+                    .with_system(splash_hold_skip::<S>)
+            );
+        } else {
+            if let Some(window) = confirm_skip {
+                on_enter(app, stage, state.clone(), insert_resource(SplashSkipConfirm {
+                    first: None,
+                    window,
+                }));
+                on_cleanup(app, stage, state.clone(), SplashCleanupSet::Resources, remove_resource::<SplashSkipConfirm>);
+            }
+            add_system_set(
+                app,
+                stage,
+                on_update(state)
+                    .label(SplashLabel::Skip)
+                    .label(SplashSet::Input)
+                    .after(SplashLabel::Clock)
+                    .with_system(splash_skip::<S>)
+            );
         }
     }
+    true
+}
 
-    if done {
-        commands.insert_resource(NextState(AppGlobalState::MainMenu));
-    }
+/// Labels for ordering your own systems relative to the splash screen's
+///
+/// All of the plugin's per-frame systems run in [`CoreStage::Update`]
+/// (or the stage set with `.stage(...)` on the plugin), while the
+/// splash screen state is active, and have one of these
+/// labels. Use them with `.before(...)`/`.after(...)` on your own systems
+/// in the same stage. For example, run your own animation systems
+/// `.before(SplashSet::Animate)`, to have their changes to splash items
+/// taken into account on the same frame.
+///
+/// Within a frame:
+///  - `Animate` advances the splash screen clock first, then drives all
+///    the splash items, and then applies the resulting colors.
+///  - `Input` runs after the clock has been advanced, but in parallel
+///    with the rest of `Animate`.
+///  - `Complete` runs after all `Input` and `Animate` systems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemLabel)]
+pub enum SplashSet {
+    /// Systems that detect the user skipping the splash screen
+    Input,
+    /// Systems that advance time, and animate the splash items
+    Animate,
+    /// Systems that check if the splash screen has ended, send
+    /// [`SplashCompleted`], and perform the state transition
+    Complete,
 }
+
+/// Labels for ordering your own exit systems relative to the splash screen's
+///
+/// When the splash screen state is exited, the plugin cleans up at the
+/// very end: after all other exit systems (the plugin's and your own)
+/// have run, and their commands have been applied. So your exit systems
+/// can always access the splash screen entities and resources, and send
+/// commands to them, regardless of ordering.
+///
+/// The cleanup is done by exclusive systems (added with `.at_end()`),
+/// with these labels, always in this order:
+///  - `Complete`: the `.on_complete(...)` closures are called.
+///  - `Despawn`: entities with [`SplashCleanup`] are despawned.
+///  - `Resources`: all of the plugin's resources are removed.
+///
+/// To run something in between, add your own exclusive exit system with
+/// `.at_end()`, and order it with `.after(...)`/`.before(...)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemLabel)]
+pub enum SplashCleanupSet {
+    /// Calls the `.on_complete(...)` closures
+    Complete,
+    /// Despawns entities with [`SplashCleanup`]
+    Despawn,
+    /// Removes the plugin's resources
+    Resources,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemLabel)]
+enum SplashLabel {
+    /// Systems that keep track of time in the splash screen
+    Clock,
+    /// Systems that detect the user skipping the splash screen
+    Skip,
+    /// Systems that drive splash items and record them in [`SplashItems`]
+    Items,
+    /// Systems that adjust splash items for the cursor ([`SplashItemHover`])
+    Hover,
+    /// The fade-in of the whole screen, which must run before the exit fade
+    ScreenFade,
+    /// Systems that apply the colors computed by splash items to entities
+    Colors,
+}
+
+/// Marker for entities that belong to the splash screen
+///
+/// All entities with this component will be despawned (recursively)
+/// when the splash screen state is exited, unless they also have
+/// [`SplashKeep`]. Insert it on all the entities you spawn for
+/// your splash screen.
+#[derive(Component)]
+pub struct SplashCleanup;
+
+/// Function to spawn custom cameras for a splash screen
+///
+/// See `.camera_setup(...)` on the splash screen plugins.
+pub type SplashCameraSetup = Arc<dyn Fn(&mut Commands) + Send + Sync>;
+
+/// Function to run when a splash screen ends
+///
+/// See `.on_complete(...)` on the splash screen plugins.
+pub type SplashCompleteHook = Arc<dyn Fn(&mut World) + Send + Sync>;
+
+/// Function to check a condition every frame, while a splash screen runs
+///
+/// See `.wait_until(...)` on [`SplashPlugin`], and `.skip_when(...)` on
+/// the splash screen plugins.
+pub type SplashCondition = Arc<dyn Fn(&World) -> bool + Send + Sync>;
+
+/// Marker for entities that should survive the end of the splash screen
+///
+/// Overrides [`SplashCleanup`]: entities with both components are left
+/// alone. Use it to keep something you have spawned for the splash screen,
+/// such as a camera you want to reuse, without having to remove the
+/// [`SplashCleanup`] from it at the right time.
+///
+/// Note that despawning is recursive: if a parent entity is cleaned up,
+/// its children are despawned with it, even if they have this component.
+///
+/// The cleanup happens at the end of the exit systems of the splash
+/// screen state, so your own exit systems will still see the entities,
+/// regardless of ordering (see [`SplashCleanupSet`]).
+#[derive(Component)]
+pub struct SplashKeep;
+
+/// Event sent when a splash screen starts
+///
+/// `state` is the state of the splash screen that started.
+///
+/// This is sent every time the state is entered. By the time your
+/// systems receive it, the plugin has finished setting up the splash
+/// screen (the same is not guaranteed for your own enter systems).
+pub struct SplashStarted<S: StateData> {
+    pub state: S,
+}
+
+/// Event sent when the user skips a splash screen
+///
+/// `state` is the state of the splash screen that was skipped.
+pub struct SplashSkipped<S: StateData> {
+    pub state: S,
+}
+
+/// Event sent when a splash screen ends
+///
+/// `state` is the state of the splash screen that ended.
+///
+/// This is sent exactly once for every run of a splash screen,
+/// whether it finished by itself, or was skipped by the user.
+pub struct SplashCompleted<S: StateData> {
+    pub state: S,
+    pub reason: SplashEndReason,
+}
+
+/// What happens when the user skips a splash screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SkipMode {
+    /// End the whole splash screen immediately
+    #[default]
+    Whole,
+    /// Only finish the group of items currently playing
+    ///
+    /// Lets the user page through items sequenced using [`SplashItemOrder`],
+    /// one group per skip. The splash screen ends when the last group is
+    /// skipped (or finishes by itself). Items without [`SplashItemOrder`]
+    /// are all in the same group, so if you do not use it, this behaves
+    /// the same as `Whole`.
+    ///
+    /// A [`SplashSkipped`] event is sent for every skipped group.
+    /// `min_duration` only delays the end of the splash screen.
+    Item,
+}
+
+/// Which clock splash screens measure time with
+///
+/// Bevy 0.8 has a single `Time` resource, which is normally updated
+/// from the system clock every frame, so both options behave the same
+/// unless your app drives `Time` itself (using `Time::update_with_instant`,
+/// to implement a game pause or slow motion, for example). Newer versions
+/// of Bevy split this into virtual time (`Res<Time>`, which can be paused
+/// and scaled) and `Res<Time<Real>>`; `Virtual` corresponds to the former.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplashClock {
+    /// Follow Bevy's `Time` resource
+    ///
+    /// If your game pauses or slows down `Time`, so do splash screens.
+    Virtual,
+    /// Measure wall-clock time, independently of `Time`
+    ///
+    /// Splash screens play normally, regardless of what happens to `Time`.
+    #[default]
+    Real,
+}
+
+/// What a splash screen does when the primary window loses focus
+///
+/// See `.on_focus_loss(...)` on the splash screen plugins. Neither clock
+/// stops while the window is unfocused: [`SplashClock::Real`] measures
+/// wall-clock time, and in Bevy 0.8 `Time` also counts the time spent
+/// unfocused, on the next frame (unless your app pauses it). So, by
+/// default, the splash screen carries on in the background, or jumps
+/// ahead on the next frame if the app stops updating while unfocused,
+/// and may have ended by the time the user comes back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplashFocusLoss {
+    /// Keep playing, as if nothing happened
+    #[default]
+    Ignore,
+    /// Pause while unfocused, and continue from where it left off
+    ///
+    /// Inserts [`SplashPaused`] (unless you already have), and removes
+    /// it again when focus comes back, or when the splash screen ends.
+    Pause,
+    /// Replay the splash screen from the beginning, when focus comes back
+    ///
+    /// Works like [`SplashCommandsExt::restart_splash`].
+    Restart,
+    /// End the splash screen as soon as focus is lost
+    ///
+    /// Also ends it when closing the window is requested, so your
+    /// `on_complete` hooks still run. It ends on that frame, with
+    /// [`SplashEndReason::Skipped`], like with [`SkipAllSplashes`]: without
+    /// an exit fade, and without waiting for `min_duration`.
+    Complete,
+}
+
+/// Fade to a color at the end of a splash screen
+///
+/// See `.exit_fade(...)` on the splash screen plugins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplashExitFade {
+    pub duration: Duration,
+    pub color: Color,
+}
+
+impl SplashExitFade {
+    /// Fade to black, over the given duration
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            color: Color::BLACK,
+        }
+    }
+
+    /// Fade to this color, instead of black
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// Fade the whole screen in and out, around a splash screen
+///
+/// See `.screen_fade(...)` on the splash screen plugins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplashScreenFade {
+    pub fade_in: Duration,
+    pub fade_out: Duration,
+    pub color: Color,
+}
+
+impl SplashScreenFade {
+    /// Fade in from black, and out to black, over the given durations
+    pub fn new(fade_in: Duration, fade_out: Duration) -> Self {
+        Self {
+            fade_in,
+            fade_out,
+            color: Color::BLACK,
+        }
+    }
+
+    /// Fade from and to this color, instead of black
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// The exit fade of the currently running splash screen, once it has started
+struct SplashExitFading {
+    timer: Timer,
+    color: Color,
+}
+
+impl SplashExitFading {
+    /// Has the fade been fully opaque for (at least) a whole frame?
+    ///
+    /// So the screen is actually displayed fully faded out once,
+    /// before the splash screen entities are despawned.
+    fn done(&self) -> bool {
+        self.timer.finished() && !self.timer.just_finished()
+    }
+}
+
+/// Marker for the full-screen node of the exit fade
+#[derive(Component)]
+struct SplashExitFadeNode;
+
+/// Start the exit fade: spawn the node, and start the timer
+fn splash_exit_fade_start(commands: &mut Commands, fade: &SplashExitFade) {
+    let mut color = fade.color;
+    color.set_a(0.0);
+    commands.spawn_bundle(splash_fade_node(color))
+    .insert(SplashExitFadeNode)
+    .insert(SplashCleanup);
+    commands.insert_resource(SplashExitFading {
+        timer: Timer::new(fade.duration, false),
+        color: fade.color,
+    });
+}
+
+fn splash_exit_fade(
+    exiting: Option<ResMut<SplashExitFading>>,
+    clock: Res<SplashTime>,
+    mut q: Query<&mut UiColor, With<SplashExitFadeNode>>,
+) {
+    let mut exiting = match exiting {
+        Some(exiting) => exiting,
+        None => return,
+    };
+    exiting.timer.tick(clock.delta);
+    let alpha = exiting.color.a() * timer_progress(&exiting.timer);
+    let mut faded = exiting.color;
+    faded.set_a(alpha);
+    for mut color in q.iter_mut() {
+        color.0 = faded;
+    }
+}
+
+/// The fade-in of the currently running splash screen, until it has finished
+struct SplashScreenFading {
+    timer: Timer,
+    color: Color,
+}
+
+/// Marker for the full-screen node of the fade-in
+#[derive(Component)]
+struct SplashScreenFadeNode;
+
+/// Full-screen node covering everything, for fading the whole screen
+fn splash_fade_node(color: Color) -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+            ..Default::default()
+        },
+        color: UiColor(color),
+        ..Default::default()
+    }
+}
+
+fn splash_screen_fade(
+    mut commands: Commands,
+    entering: Option<ResMut<SplashScreenFading>>,
+    exiting: Option<ResMut<SplashExitFading>>,
+    clock: Res<SplashTime>,
+    mut q: Query<(Entity, &mut UiColor), With<SplashScreenFadeNode>>,
+) {
+    let mut entering = match entering {
+        Some(entering) => entering,
+        None => return,
+    };
+    entering.timer.tick(clock.delta);
+    let opacity = 1.0 - timer_progress(&entering.timer);
+    let exit_started = match exiting {
+        Some(mut exiting) if exiting.is_added() => {
+            // fade out from the current opacity, instead of from transparent
+            let elapsed = exiting.timer.duration().mul_f32(opacity);
+            exiting.timer.set_elapsed(elapsed);
+            true
+        }
+        _ => false,
+    };
+    if entering.timer.finished() || exit_started {
+        commands.remove_resource::<SplashScreenFading>();
+        for (e, _) in q.iter() {
+            commands.entity(e).despawn_recursive();
+        }
+        return;
+    }
+    let mut faded = entering.color;
+    faded.set_a(entering.color.a() * opacity);
+    for (_, mut color) in q.iter_mut() {
+        color.0 = faded;
+    }
+}
+
+/// Observe splash screens ending, from outside the ECS
+///
+/// Create one, give a clone to `.signal(...)` on a splash screen
+/// plugin, and keep another clone for yourself, to check on (or
+/// `await`) the splash screen from anywhere, such as from an async
+/// task. All clones share the same state.
+///
+/// The signal fires exactly once per run of the splash screen (on the
+/// same frame a [`SplashCompleted`] event is sent), however it ended.
+///
+/// It is cheap to clone, and safe to use from any thread: the state
+/// is protected by a mutex, which is only ever held very briefly.
+#[derive(Clone, Default)]
+pub struct SplashSignal(Arc<std::sync::Mutex<SplashSignalInner>>);
+
+#[derive(Default)]
+struct SplashSignalInner {
+    runs: u32,
+    last: Option<SplashEndReason>,
+    wakers: Vec<std::task::Waker>,
+}
+
+impl SplashSignal {
+    /// Create a new signal, that has not fired yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SplashSignalInner> {
+        // the state is always consistent, even if somebody panicked
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// How many times the splash screen has ended so far
+    pub fn runs(&self) -> u32 {
+        self.lock().runs
+    }
+
+    /// Why the splash screen ended the last time, if it has ended yet
+    pub fn last(&self) -> Option<SplashEndReason> {
+        self.lock().last
+    }
+
+    /// Wait until the splash screen has ended
+    ///
+    /// The returned future resolves as soon as the signal has fired at
+    /// least once (immediately, if it already has), with the reason the
+    /// splash screen ended the last time.
+    pub fn wait(&self) -> SplashSignalWait {
+        SplashSignalWait(self.clone())
+    }
+
+    fn fire(&self, reason: SplashEndReason) {
+        let wakers = {
+            let mut inner = self.lock();
+            inner.runs += 1;
+            inner.last = Some(reason);
+            std::mem::take(&mut inner.wakers)
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+/// Future returned by [`SplashSignal::wait`]
+pub struct SplashSignalWait(SplashSignal);
+
+impl std::future::Future for SplashSignalWait {
+    type Output = SplashEndReason;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        let mut inner = self.0.lock();
+        if let Some(reason) = inner.last {
+            return std::task::Poll::Ready(reason);
+        }
+        if !inner.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            inner.wakers.push(cx.waker().clone());
+        }
+        std::task::Poll::Pending
+    }
+}
+
+/// Why a splash screen ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplashEndReason {
+    /// All splash items finished displaying
+    ///
+    /// The splash screen ends on the frame after the last items finish,
+    /// so items you spawn on the frame they finish (such as after noticing
+    /// that they did) still count, and hold up the end of it.
+    Completed,
+    /// The user skipped the splash screen
+    Skipped,
+    /// The splash screen ran for longer than its `max_duration`
+    TimedOut,
+}
+
+/// Extension trait for controlling splash screens using [`Commands`]
+pub trait SplashCommandsExt<'w, 's> {
+    /// Replay the currently running splash screen from the beginning
+    ///
+    /// This re-enters the current state of type `S`, so all splash screen
+    /// entities are cleaned up and all enter systems run again (both the
+    /// plugin's and your own), just like when the state was first entered.
+    ///
+    /// Does nothing if no splash screen is currently running.
+    fn restart_splash<S: StateData>(&mut self);
+
+    /// Spawn an entity belonging to the currently running splash screen
+    ///
+    /// The entity gets [`SplashCleanup`] and is added as a child of the
+    /// [`SplashRoot`] entity, so you do not have to remember either.
+    ///
+    /// Do not use this for UI nodes: they must only be parented to other
+    /// UI nodes, so spawn them with [`SplashCleanup`] yourself.
+    fn spawn_splash_item<'a>(&'a mut self, bundle: impl Bundle) -> EntityCommands<'w, 's, 'a>;
+
+    /// Spawn a 2D text splash item, laid out for the direction of its text
+    ///
+    /// Spawned like with `spawn_splash_item`, using a [`Text2dBundle`].
+    /// The text is aligned to the edge where it starts: the entity's
+    /// translation is the left edge of left-to-right text, or the right
+    /// edge of right-to-left text, and every line is aligned to it. It is
+    /// centered vertically.
+    ///
+    /// Bevy does not support right-to-left text itself, so each line is
+    /// reordered for display. Lines can mix directions, such as an
+    /// English studio name in Arabic text: runs in the other direction,
+    /// and numbers, are kept in the correct order. Note that letters are
+    /// not shaped (for example, Arabic letters are not joined), so use a
+    /// font or text that does not require it.
+    ///
+    /// Insert [`SplashItemFade`] (or any other splash item) on the
+    /// returned entity, to animate it.
+    fn spawn_splash_text<'a>(
+        &'a mut self,
+        text: &str,
+        style: TextStyle,
+        direction: SplashTextDirection,
+    ) -> EntityCommands<'w, 's, 'a>;
+
+    /// Do not end the currently running splash screen until these assets have loaded
+    ///
+    /// Like `.wait_for_assets(...)` on [`SplashPlugin`], but for assets you
+    /// start loading while the splash screen is running (for example, in
+    /// your enter systems). Only use it while a splash screen is running,
+    /// or the assets will hold up the next splash screen instead.
+    fn wait_for_splash_assets(&mut self, handles: impl IntoIterator<Item = HandleUntyped>);
+}
+
+impl<'w, 's> SplashCommandsExt<'w, 's> for Commands<'w, 's> {
+    fn restart_splash<S: StateData>(&mut self) {
+        self.add(RestartSplash::<S>(std::marker::PhantomData));
+    }
+
+    fn spawn_splash_item<'a>(&'a mut self, bundle: impl Bundle) -> EntityCommands<'w, 's, 'a> {
+        let entity = self.spawn_bundle(bundle).insert(SplashCleanup).id();
+        self.add(AddToSplashRoot(entity));
+        self.entity(entity)
+    }
+
+    fn spawn_splash_text<'a>(
+        &'a mut self,
+        text: &str,
+        style: TextStyle,
+        direction: SplashTextDirection,
+    ) -> EntityCommands<'w, 's, 'a> {
+        let rtl = direction.is_rtl(text);
+        let value = text
+            .lines()
+            .map(|line| bidi_reorder_line(line, rtl))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let alignment = TextAlignment {
+            vertical: VerticalAlign::Center,
+            horizontal: if rtl { HorizontalAlign::Right } else { HorizontalAlign::Left },
+        };
+        self.spawn_splash_item(Text2dBundle {
+            text: Text::from_section(value, style).with_alignment(alignment),
+            ..Default::default()
+        })
+    }
+
+    fn wait_for_splash_assets(&mut self, handles: impl IntoIterator<Item = HandleUntyped>) {
+        self.add(AddSplashAssets(handles.into_iter().collect()));
+    }
+}
+
+/// The root entity of the currently running splash screen
+///
+/// Every splash screen gets a new root entity when it starts. It has a
+/// [`SpatialBundle`] (at the origin) and [`SplashCleanup`], so all of its
+/// children are despawned with it, when the splash screen ends. Use it
+/// as the parent for your splash screen entities, or spawn them using
+/// [`SplashCommandsExt::spawn_splash_item`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplashRoot(pub Entity);
+
+/// Create the [`SplashRoot`], if it does not exist yet
+///
+/// Every enter system might want to use the root, and the order in
+/// which their commands are applied is unknown, so whichever comes
+/// first creates it.
+struct EnsureSplashRoot;
+
+impl bevy::ecs::system::Command for EnsureSplashRoot {
+    fn write(self, world: &mut World) {
+        if !world.contains_resource::<SplashRoot>() {
+            let root = world.spawn()
+                .insert_bundle(SpatialBundle::default())
+                .insert(SplashCleanup)
+                .id();
+            world.insert_resource(SplashRoot(root));
+        }
+    }
+}
+
+struct AddToSplashRoot(Entity);
+
+impl bevy::ecs::system::Command for AddToSplashRoot {
+    fn write(self, world: &mut World) {
+        EnsureSplashRoot.write(world);
+        let root = world.resource::<SplashRoot>().0;
+        world.entity_mut(root).push_children(&[self.0]);
+    }
+}
+
+/// Is the currently running splash screen waiting for `.wait_until(...)`?
+///
+/// Updated every frame by [`splash_condition_check`].
+struct SplashWaiting(bool);
+
+/// Check the `.wait_until(...)` closures, and record the result
+fn splash_condition_check(conditions: Vec<SplashCondition>) -> impl FnMut(&mut World) {
+    move |world| {
+        let ready = conditions.iter().all(|condition| condition(world));
+        world.insert_resource(SplashWaiting(!ready));
+    }
+}
+
+/// Did the `.skip_when(...)` closure return `true` on this frame?
+///
+/// Updated every frame by [`splash_skip_trigger`].
+struct SplashSkipTriggered(bool);
+
+/// Check the `.skip_when(...)` closure, and record the result
+fn splash_skip_trigger(condition: SplashCondition) -> impl FnMut(&mut World) {
+    move |world| {
+        let triggered = condition(world);
+        world.insert_resource(SplashSkipTriggered(triggered));
+    }
+}
+
+/// Assets that the currently running splash screen is waiting for
+///
+/// Created on enter, from the plugin config, or by the first
+/// [`AddSplashAssets`], whichever is applied first.
+#[derive(Default)]
+struct SplashAssets {
+    /// Assets that have not finished loading yet
+    pending: Vec<HandleUntyped>,
+}
+
+struct AddSplashAssets(Vec<HandleUntyped>);
+
+impl bevy::ecs::system::Command for AddSplashAssets {
+    fn write(self, world: &mut World) {
+        world.get_resource_or_insert_with(SplashAssets::default)
+            .pending
+            .extend(self.0);
+    }
+}
+
+struct RestartSplash<S: StateData>(std::marker::PhantomData<S>);
+
+impl<S: StateData> bevy::ecs::system::Command for RestartSplash<S> {
+    fn write(self, world: &mut World) {
+        if let Some(current) = world.get_resource::<SplashCurrent<S>>() {
+            let current = current.0.clone();
+            SplashTransition(current).write(world);
+        }
+    }
+}
+
+/// Queue a transition to another state, using whichever
+/// state backend the crate was compiled with
+struct SplashTransition<S: StateData>(S);
+
+#[cfg(feature = "iyes_loopless")]
+impl<S: StateData> bevy::ecs::system::Command for SplashTransition<S> {
+    fn write(self, world: &mut World) {
+        world.insert_resource(NextState(self.0));
+    }
+}
+
+#[cfg(not(feature = "iyes_loopless"))]
+impl<S: StateData> bevy::ecs::system::Command for SplashTransition<S> {
+    fn write(self, world: &mut World) {
+        let mut state = world.resource_mut::<State<S>>();
+        if *state.current() == self.0 {
+            // Bevy refuses to `set` the state it is already in
+            let _ = state.restart();
+        } else {
+            let _ = state.set(self.0);
+        }
+    }
+}
+
+/// Insert this resource to skip all splash screens, during development
+///
+/// While it is set to `true`, every splash screen ends on the first
+/// frame, without spawning its camera, and without waiting for
+/// `min_duration`. [`SplashCompleted`] is still sent, with the reason
+/// [`SplashEndReason::Skipped`] (but [`SplashSkipped`] is not). You
+/// should avoid spawning your own splash screen entities, if it is set.
+///
+/// In debug builds, it is inserted automatically if the `IYES_SPLASH_SKIP`
+/// environment variable is set (to anything). In release builds, it only
+/// has an effect if you insert it yourself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Resource)]
+pub struct SkipAllSplashes(pub bool);
+
+/// Exists while the splash screen is waiting for the user to interact
+///
+/// Only with splash screens configured with `.require_interaction(true)`.
+/// Display a prompt (such as "click to start") while it exists. It is
+/// removed on the first keyboard key, mouse button, or touch press.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SplashAwaitingInteraction;
+
+/// Condition for `.require_interaction(true)`, so the splash screen cannot end before it
+fn splash_interaction_condition() -> SplashCondition {
+    Arc::new(|world: &World| !world.contains_resource::<SplashAwaitingInteraction>())
+}
+
+fn splash_interaction(
+    mut commands: Commands,
+    awaiting: Option<Res<SplashAwaitingInteraction>>,
+    keys: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    touches: Res<Touches>,
+) {
+    if awaiting.is_none() {
+        return;
+    }
+    // these are the kinds of input that let browsers play sound
+    if keys.get_just_pressed().len() > 0
+        || mouse.get_just_pressed().len() > 0
+        || touches.any_just_pressed()
+    {
+        commands.remove_resource::<SplashAwaitingInteraction>();
+    }
+}
+
+/// Insert this resource to pause the splash screen
+///
+/// While it exists, all splash items freeze and skip input is ignored.
+/// Remove it to resume from where things left off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Resource)]
+pub struct SplashPaused;
+
+/// Exists while the splash screen is paused by [`SplashFocusLoss::Pause`]
+///
+/// So that we never remove a [`SplashPaused`] inserted by the user.
+struct SplashFocusPaused;
+
+#[allow(clippy::too_many_arguments)]
+fn splash_focus<S: StateData>(
+    mut commands: Commands,
+    mut focus_reader: Local<ManualEventReader<WindowFocused>>,
+    mut close_reader: Local<ManualEventReader<WindowCloseRequested>>,
+    focus_events: Option<Res<Events<WindowFocused>>>,
+    close_events: Option<Res<Events<WindowCloseRequested>>>,
+    mut config: ResMut<SplashConfig>,
+    ended: Res<SplashEnded>,
+    paused: Option<Res<SplashPaused>>,
+    focus_paused: Option<Res<SplashFocusPaused>>,
+    mut lost: Local<bool>,
+) {
+    // windows usually report being focused when they open, which does
+    // not count as coming back, so only do that after losing focus
+    if config.is_added() {
+        *lost = false;
+    }
+    // `iter` must run to the end, so we do not see the same events next frame
+    let mut focused = None;
+    if let Some(events) = focus_events {
+        for ev in focus_reader.iter(&events).filter(|ev| ev.id.is_primary()) {
+            focused = Some(ev.focused);
+        }
+    }
+    let close = close_events.is_some_and(|events| {
+        close_reader.iter(&events).filter(|ev| ev.id.is_primary()).count() > 0
+    });
+    let returned = focused == Some(true) && *lost;
+    if let Some(focused) = focused {
+        *lost = !focused;
+    }
+    match (config.focus_loss, focused) {
+        // (even if it has ended, anything still playing must resume)
+        (SplashFocusLoss::Pause, Some(true)) if focus_paused.is_some() => {
+            commands.remove_resource::<SplashPaused>();
+            commands.remove_resource::<SplashFocusPaused>();
+        }
+        _ if ended.0.is_some() => {}
+        (SplashFocusLoss::Complete, _) if close || focused == Some(false) => {
+            config.skip_all = true;
+        }
+        (SplashFocusLoss::Pause, Some(false)) if paused.is_none() => {
+            commands.insert_resource(SplashPaused);
+            commands.insert_resource(SplashFocusPaused);
+        }
+        (SplashFocusLoss::Restart, Some(true)) if returned => {
+            commands.restart_splash::<S>();
+        }
+        _ => {}
+    }
+}
+
+/// Undo the pause of [`SplashFocusLoss::Pause`], if the splash screen ends while unfocused
+fn splash_focus_cleanup(world: &mut World) {
+    if world.remove_resource::<SplashFocusPaused>().is_some() {
+        world.remove_resource::<SplashPaused>();
+    }
+}
+
+/// Insert this resource to speed up or slow down all splash screens
+///
+/// All splash screen time is multiplied by this value, so `2.0` plays
+/// everything twice as fast, and `0.5` twice as slow. This includes
+/// timing options like `skip_after` and `min_duration`.
+///
+/// Values of zero (or negative values) freeze the splash screen, like
+/// [`SplashPaused`], except that skip input is still accepted. Huge
+/// values (including infinity) play everything out on the next frame:
+/// a single frame never advances the splash screen by more than
+/// [`SplashTimeScale::MAX_STEP`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct SplashTimeScale(pub f32);
+
+impl SplashTimeScale {
+    /// The most a single frame can advance the splash screen by, whatever the scale
+    ///
+    /// A year: long enough for anything to finish, while leaving plenty
+    /// of room before the timers of splash items would overflow.
+    pub const MAX_STEP: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+}
+
+impl Default for SplashTimeScale {
+    fn default() -> Self {
+        SplashTimeScale(1.0)
+    }
+}
+
+/// Insert this resource to control splash screen time manually, such as in tests
+///
+/// While it exists, splash screens advance by exactly this much time
+/// on every frame (every `app.update()`), instead of measuring the time
+/// that has actually passed, regardless of the `clock` setting. This lets
+/// you step through a splash screen deterministically, in a headless app.
+/// [`SplashTimeScale`] and [`SplashPaused`] still apply on top of it.
+///
+/// Holding input to skip (see `.hold_to_skip(...)`) is measured in
+/// splash screen time, so it also advances by this much.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Resource)]
+pub struct SplashTestClock(pub Duration);
+
+/// The state of the currently running splash screen
+struct SplashCurrent<S: StateData>(S);
+
+/// The state to transition to, when each splash screen completes
+///
+/// Keyed by the state of the splash screen.
+struct SplashNext<S: StateData>(HashMap<S, S>);
+
+/// All states that have a splash screen plugin
+struct SplashStates<S: StateData>(HashSet<S>);
+
+/// The state that was active as of the start of the current frame
+///
+/// Only tracked if a splash screen uses `.return_to_previous(true)`.
+struct SplashLastState<S: StateData>(Option<S>);
+
+/// The state to return to, when the splash screen completes
+///
+/// Overrides [`SplashNext`], if set, for the splash screen in
+/// the `splash` state. Kept after the splash screen exits, so
+/// that it survives restarts.
+struct SplashReturn<S: StateData> {
+    splash: S,
+    previous: Option<S>,
+}
+
+/// How [`SplashProgressPlugin`] reports the splash screen to `iyes_progress`
+///
+/// Either way, the splash screen holds up the `iyes_progress` state
+/// transition until it has ended. The difference is only in how it
+/// affects the overall progress percentage (as displayed by a loading
+/// bar, or `SplashProgressText`), which is `done / total`, summed over
+/// all your progress-tracking systems.
+#[cfg(feature = "iyes_progress")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressVisibility {
+    /// Report the splash screen's progress, as it plays
+    ///
+    /// The splash screen counts as `progress_weight` units of work, which
+    /// get done gradually, as its items advance. The overall percentage
+    /// moves with the splash screen, even if nothing else is happening.
+    #[default]
+    Visible,
+    /// Only report whether the splash screen has ended
+    ///
+    /// The splash screen counts as a single unit of work, done when it
+    /// ends, regardless of `progress_weight`. The overall percentage is
+    /// (almost) entirely made up of your other work, such as loading
+    /// assets. It does not reach 100% before the splash screen has ended.
+    ///
+    /// `iyes_progress` 0.4 does not have a separate hidden counter, so
+    /// this single unit is still included in the percentage.
+    Hidden,
+}
+
+/// Settings specific to [`SplashProgressPlugin`]
+#[cfg(feature = "iyes_progress")]
+#[derive(Clone)]
+struct SplashProgressConfig {
+    weight: u32,
+    visibility: ProgressVisibility,
+}
+
+/// Progress of all other work, for [`SplashProgressPlugin`]'s `skip_min_progress`
+///
+/// Only exists if it is set.
+#[derive(Clone, Default)]
+struct SplashLoadProgress {
+    /// How much progress is needed, for skipping
+    min: f32,
+    /// Progress of everything except the splash screen, as of the end of the last frame
+    fraction: f32,
+    /// What the splash screen itself reported on the last frame (`done`, `total`)
+    #[cfg(feature = "iyes_progress")]
+    own: (u32, u32),
+}
+
+/// Settings of the currently running splash screen
+#[derive(Clone)]
+struct SplashConfig {
+    skippable: bool,
+    skip_after: Duration,
+    skip_once_visible: bool,
+    min_duration: Duration,
+    skip_keys: Option<Vec<KeyCode>>,
+    skip_keyboard: bool,
+    skip_mouse: bool,
+    skip_gamepad: bool,
+    gamepad_threshold: f32,
+    skip_touch: bool,
+    hold_to_skip: Option<Duration>,
+    confirm_skip: Option<Duration>,
+    skip_mode: SkipMode,
+    /// Replaces the built-in skip input, if set
+    skip_when: Option<SplashCondition>,
+    background: Option<Color>,
+    clear: bool,
+    spawn_camera: bool,
+    camera_layers: Option<RenderLayers>,
+    camera_scaling: Option<ScalingMode>,
+    camera_setup: Option<SplashCameraSetup>,
+    clock: SplashClock,
+    focus_loss: SplashFocusLoss,
+    on_complete: Vec<SplashCompleteHook>,
+    signal: Option<SplashSignal>,
+    exit_fade: Option<SplashExitFade>,
+    /// Only used for fading in, the fade out is in `exit_fade`
+    screen_fade: Option<SplashScreenFade>,
+    require_interaction: bool,
+    #[cfg(feature = "audio")]
+    music_fade_out: Option<Duration>,
+    /// Where all the per-frame systems go
+    stage: StageLabelId,
+    max_duration: Option<Duration>,
+    assets: Vec<HandleUntyped>,
+    conditions: Vec<SplashCondition>,
+    skip_waits_for_condition: bool,
+    /// Set on enter, from [`SkipAllSplashes`] (or on focus loss, with
+    /// [`SplashFocusLoss::Complete`])
+    skip_all: bool,
+    /// Covers the whole splash screen, for logging; created on enter
+    span: Span,
+}
+
+/// Time as seen by the splash screen
+///
+/// Does not advance while [`SplashPaused`] exists. All systems
+/// that drive splash items should tick using `delta`.
+#[derive(Default)]
+struct SplashTime {
+    /// How long the splash screen has been running for
+    elapsed: Duration,
+    /// How much time has passed on this frame
+    delta: Duration,
+    /// When we last measured, for [`SplashClock::Real`]
+    last_update: Option<Instant>,
+}
+
+/// Information about the currently running splash screen
+///
+/// Exists while a splash screen is running, and is updated every
+/// frame, after all splash items have advanced. Use it if you want to
+/// display a countdown, or otherwise react to the splash screen's timing.
+#[derive(Debug, Clone, Default)]
+pub struct SplashState {
+    /// How long the splash screen has been running for
+    ///
+    /// Does not advance while [`SplashPaused`] exists.
+    pub elapsed: Duration,
+    /// Estimated time until all splash items finish
+    ///
+    /// Takes [`SplashItemOrder`] into account. Does not include any
+    /// time added by `min_duration`, or by events outside of the plugin's
+    /// control (such as the user skipping). `None` if some item only
+    /// finishes when the user skips (see [`SplashItemFade::until_skipped`]).
+    pub remaining: Option<Duration>,
+    /// Has the user requested to skip the whole splash screen?
+    ///
+    /// If it has not ended yet, it will as soon as `min_duration` allows.
+    pub skip_requested: bool,
+}
+
+/// Tally of all splash items, accumulated every frame
+///
+/// Every system that drives splash items records each of them here.
+/// `splash_complete` then checks and resets it, after all of them have run.
+#[derive(Default)]
+struct SplashItems {
+    count: u32,
+    unfinished: u32,
+    progress: f32,
+    /// The [`SplashItemOrder`] group currently playing (kept across resets)
+    active: u32,
+    /// The lowest group with unfinished items, seen on this frame
+    next_active: Option<u32>,
+    /// All groups up to this one have been skipped (kept across resets)
+    skipped: Option<u32>,
+    /// Longest remaining time of any item, for each group (`None` if endless)
+    remaining: BTreeMap<u32, Option<Duration>>,
+    /// Whether any items (even optional ones) have been seen yet (kept across resets)
+    spawned: bool,
+    /// Whether all items had already finished on the previous frame (set on reset)
+    settled: bool,
+    /// Whether any items that control their own visibility have been seen yet (kept across resets)
+    faded: bool,
+    /// Whether any of those have started to appear yet (kept across resets)
+    shown: bool,
+}
+
+impl SplashItems {
+    /// Is the item's group allowed to play yet?
+    fn is_active(&self, order: Option<&SplashItemOrder>) -> bool {
+        order.map(|order| order.0).unwrap_or(0) <= self.active
+    }
+
+    /// Has the item's group been skipped (with [`SkipMode::Item`])?
+    fn is_skipped(&self, order: Option<&SplashItemOrder>) -> bool {
+        let order = order.map(|order| order.0).unwrap_or(0);
+        self.skipped.is_some_and(|skipped| order <= skipped)
+    }
+
+    /// Has the last group to play been skipped?
+    fn skipped_last(&self) -> bool {
+        self.all_finished() && self.skipped == Some(self.active)
+    }
+
+    /// How much time the item should advance by, on this frame
+    fn delta(&self, order: Option<&SplashItemOrder>, clock: &SplashTime) -> Duration {
+        if self.is_active(order) {
+            clock.delta
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// Record an item, with its progress in the `0.0..=1.0` range
+    fn track(
+        &mut self,
+        order: Option<&SplashItemOrder>,
+        optional: Option<&SplashItemOptional>,
+        finished: bool,
+        progress: f32,
+        remaining: Option<Duration>,
+    ) {
+        self.mark_spawned();
+        if optional.is_some() {
+            return;
+        }
+        self.count += 1;
+        if !finished {
+            self.unfinished += 1;
+            let order = order.map(|order| order.0).unwrap_or(0);
+            self.next_active = Some(self.next_active.map_or(order, |next| next.min(order)));
+            let group = self.remaining.entry(order).or_insert(Some(Duration::ZERO));
+            *group = group.zip(remaining).map(|(group, remaining)| group.max(remaining));
+        }
+        self.progress += if finished { 1.0 } else { progress.clamp(0.0, 1.0) };
+    }
+
+    /// Record that an item exists, even if it does not count towards completion
+    ///
+    /// Skipping is only allowed once something has been seen, so every
+    /// item system must call this (`track` does it implicitly).
+    fn mark_spawned(&mut self) {
+        self.spawned = true;
+    }
+
+    /// Record whether an item has started to appear, for `skip_once_visible`
+    fn track_shown(&mut self, shown: bool) {
+        self.faded = true;
+        self.shown |= shown;
+    }
+
+    /// Has anything been shown yet, for `skip_once_visible`?
+    ///
+    /// Counts as shown, if no items control their own alpha.
+    fn any_shown(&self) -> bool {
+        self.shown || !self.faded
+    }
+
+    /// Average progress of all items
+    #[cfg(feature = "iyes_progress")]
+    fn progress(&self) -> f32 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.progress / self.count as f32
+        }
+    }
+
+    /// Record an item driven by a single timer
+    fn track_timer(
+        &mut self,
+        order: Option<&SplashItemOrder>,
+        optional: Option<&SplashItemOptional>,
+        timer: &Timer,
+    ) {
+        let remaining = timer.duration().saturating_sub(timer.elapsed());
+        self.track(order, optional, timer.finished(), timer_progress(timer), Some(remaining));
+    }
+
+    /// Estimated time until all items finish, with groups playing in sequence
+    fn remaining(&self) -> Option<Duration> {
+        self.remaining.values().copied().sum()
+    }
+
+    fn all_finished(&self) -> bool {
+        self.count > 0 && self.unfinished == 0
+    }
+
+    /// Have all items finished, and stayed finished for a whole frame?
+    ///
+    /// Items spawned on the frame when the others finish (such as in
+    /// reaction to it) are only seen on the next frame, so they get the
+    /// chance to hold up the end of the splash screen.
+    fn all_settled(&self) -> bool {
+        self.settled && self.all_finished()
+    }
+
+    /// Clear the tally for the next frame, moving on to the next group if needed
+    fn reset(&mut self) {
+        *self = SplashItems {
+            active: self.next_active.unwrap_or(self.active),
+            skipped: self.skipped,
+            spawned: self.spawned,
+            settled: self.all_finished(),
+            faded: self.faded,
+            shown: self.shown,
+            ..Default::default()
+        };
+    }
+}
+
+/// Set when the user has chosen to skip the splash screen
+///
+/// Exists only while a splash screen is running, and is set by the input
+/// that skips it. With [`SkipMode::Item`], only the
+/// current group of items is skipped, so it stays `false`. Use
+/// [`splash_skip_requested`] to run your own systems only after a skip,
+/// such as to fast-forward your own animations.
+#[derive(Debug, Default)]
+pub struct SplashSkipRequested(bool);
+
+impl SplashSkipRequested {
+    /// Has the splash screen been skipped?
+    pub fn get(&self) -> bool {
+        self.0
+    }
+}
+
+/// Run condition: has the running splash screen been skipped?
+///
+/// For use with `.run_if(splash_skip_requested)`. It is `false` when no
+/// splash screen is running. See [`SplashSkipRequested`].
+pub fn splash_skip_requested(skip: Option<Res<SplashSkipRequested>>) -> bool {
+    skip.is_some_and(|skip| skip.0)
+}
+
+/// Progress of holding down input to skip the splash screen
+///
+/// Only exists during splash screens configured with `.hold_to_skip(...)`.
+/// Use it if you want to display the progress to the user.
+#[derive(Debug, Clone)]
+pub struct SplashSkipHold {
+    /// How long the input has been held for, in splash screen time
+    pub held: Duration,
+    /// How long the input must be held for, to skip
+    pub required: Duration,
+}
+
+impl SplashSkipHold {
+    /// Get the progress of the hold, in the `0.0..=1.0` range
+    pub fn progress(&self) -> f32 {
+        if self.required.is_zero() {
+            return 1.0;
+        }
+        (self.held.as_secs_f32() / self.required.as_secs_f32()).min(1.0)
+    }
+}
+
+/// The press waiting to be confirmed, to skip the splash screen
+///
+/// Only exists during splash screens configured with `.confirm_skip(...)`.
+/// Use it if you want to display a hint, such as "tap again to skip",
+/// while `pending()` returns `true`.
+#[derive(Debug, Clone)]
+pub struct SplashSkipConfirm {
+    /// When the first press happened, in splash screen time
+    /// (see [`SplashState::elapsed`]), if there was one recently
+    pub first: Option<Duration>,
+    /// How soon the second press must come, after the first
+    pub window: Duration,
+}
+
+impl SplashSkipConfirm {
+    /// Is a first press waiting for the second one?
+    pub fn pending(&self) -> bool {
+        self.first.is_some()
+    }
+}
+
+/// Set when the splash screen has ended, and why
+#[derive(Default)]
+struct SplashEnded(Option<SplashEndReason>);
+
+#[allow(clippy::type_complexity)]
+fn splash_init<S: StateData>(
+    config: SplashConfig,
+    state: S,
+) -> impl FnMut(Commands, EventWriter<SplashStarted<S>>, Option<Res<SkipAllSplashes>>) {
+    use bevy::core_pipeline::clear_color::ClearColorConfig;
+
+    move |mut commands, mut evw, skip_all| {
+        let mut config = config.clone();
+        config.skip_all = skip_all.is_some_and(|skip_all| skip_all.0);
+        // lives as long as the config resource, until the cleanup
+        config.span = info_span!("splash", state = ?state);
+        let _span = config.span.enter();
+        info!(state = ?state, "Splash screen started");
+        if config.skip_all {
+            debug!("Skipping it immediately, because of SkipAllSplashes");
+        }
+        commands.insert_resource(config.clone());
+        commands.insert_resource(SplashCurrent(state.clone()));
+        commands.insert_resource(SplashItems::default());
+        commands.insert_resource(SplashSkipRequested::default());
+        commands.insert_resource(SplashEnded::default());
+        commands.insert_resource(SplashTime::default());
+        commands.insert_resource(SplashState::default());
+        commands.add(EnsureSplashRoot);
+        commands.add(AddSplashAssets(config.assets.clone()));
+        if config.require_interaction && !config.skip_all {
+            commands.insert_resource(SplashAwaitingInteraction);
+        }
+        if config.skip_all {
+            // nothing is going to be displayed
+        } else if let Some(setup) = &config.camera_setup {
+            setup(&mut commands);
+        } else if config.spawn_camera {
+            let mut camera = Camera2dBundle::default();
+            if !config.clear {
+                camera.camera_2d.clear_color = ClearColorConfig::None;
+                camera.camera.priority = 1;
+            } else if let Some(color) = config.background {
+                camera.camera_2d.clear_color = ClearColorConfig::Custom(color);
+            }
+            if let Some(scaling) = &config.camera_scaling {
+                camera.projection.scaling_mode = scaling.clone();
+            }
+            let mut camera = commands.spawn_bundle(camera);
+            camera.insert(SplashCleanup);
+            if let Some(layers) = config.camera_layers {
+                camera.insert(layers);
+            }
+        }
+        if let Some(fade) = config.screen_fade.filter(|_| !config.skip_all) {
+            commands.spawn_bundle(splash_fade_node(fade.color))
+                .insert(SplashScreenFadeNode)
+                .insert(SplashCleanup);
+            commands.insert_resource(SplashScreenFading {
+                timer: Timer::new(fade.fade_in, false),
+                color: fade.color,
+            });
+        }
+        evw.send(SplashStarted {
+            state: state.clone(),
+        });
+    }
+}
+
+#[cfg(feature = "iyes_loopless")]
+fn splash_track_state<S: StateData>(
+    mut last: ResMut<SplashLastState<S>>,
+    state: Option<Res<CurrentState<S>>>,
+) {
+    if let Some(state) = state {
+        last.0 = Some(state.0.clone());
+    }
+}
+
+#[cfg(not(feature = "iyes_loopless"))]
+fn splash_track_state<S: StateData>(
+    mut last: ResMut<SplashLastState<S>>,
+    state: Option<Res<State<S>>>,
+) {
+    if let Some(state) = state {
+        last.0 = Some(state.current().clone());
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn splash_return_init<S: StateData>(
+    state: S,
+) -> impl FnMut(Commands, Res<SplashLastState<S>>, Option<Res<SplashReturn<S>>>) {
+    move |mut commands, last, ret| {
+        // if the app started in the splash screen, there is nothing to return to
+        let previous = last.0.clone().filter(|last| *last != state);
+        // if the splash screen was restarted, keep returning to the same place
+        let restarted = ret.map(|ret| ret.splash == state).unwrap_or(false);
+        if previous.is_some() || !restarted {
+            commands.insert_resource(SplashReturn {
+                splash: state.clone(),
+                previous,
+            });
+        }
+    }
+}
+
+fn insert_resource<T: Clone + Send + Sync + 'static>(value: T) -> impl FnMut(Commands) {
+    move |mut commands: Commands| {
+        commands.insert_resource(value.clone());
+    }
+}
+
+fn splash_cleanup(world: &mut World) {
+    let entities: Vec<Entity> = world
+        .query_filtered::<Entity, (With<SplashCleanup>, Without<SplashKeep>)>()
+        .iter(world)
+        .collect();
+    for e in entities {
+        // might already be gone, despawned along with its parent
+        if let Some(entity) = world.get_entity_mut(e) {
+            entity.despawn_recursive();
+        }
+    }
+}
+
+fn remove_resource<T: Send + Sync + 'static>(world: &mut World) {
+    world.remove_resource::<T>();
+}
+
+/// Splash screen item that fades in and out
+///
+/// Works with sprites ([`Sprite`]), UI nodes ([`UiColor`]),
+/// text ([`Text`]), and 2D meshes ([`Handle<ColorMaterial>`]).
+/// For text, every section is faded together, keeping its own
+/// color and relative alpha.
+///
+/// As materials may be shared by many entities, the material of
+/// a 2D mesh is copied on the first frame, and the entity's handle
+/// is replaced with one to the copy, which is then modified every
+/// frame. Every changed material needs to be re-uploaded to the GPU,
+/// so prefer sprites if you have a lot of items.
+///
+/// Goes through four phases, one after the other:
+///  - wait: the item is fully transparent
+///  - intro: the item fades in, following `intro_ease`
+///  - on: the item is fully opaque
+///  - fade: the item fades out, following `fade_ease`
+///
+/// Easing only applies to the intro and fade phases. The item
+/// snaps to fully transparent/opaque, for the wait and on phases.
+///
+/// Any phase can have zero duration, in which case it is skipped
+/// over instantly. For example, with no intro, the item appears
+/// fully opaque right away. With neither an on nor a fade phase, it
+/// is still shown fully opaque for one frame, before it vanishes.
+///
+/// The item is finished when all four phases have completed.
+///
+/// Phases normally last a given time, but can also last a given number
+/// of frames instead (see [`SplashItemFade::frames`]).
+///
+/// If the entity has an image ([`Handle<Image>`], as sprites do, or
+/// [`UiImage`]) that has not loaded yet, the item plays its wait phase,
+/// but then holds until the image has loaded (or failed to load), so the
+/// image does not pop in halfway through the intro. The splash screen
+/// waits for it, too. To have your logo ready right away, load it in a
+/// state before the splash screen, and only enter the splash screen once
+/// it has loaded; the rest of your assets can then load while the splash
+/// screen displays (see [`SplashPlugin::wait_for_assets`]).
+///
+/// It can be edited through reflection, such as in an inspector, to
+/// tweak the timings live: the timers of all four phases, and the other
+/// settings, are reflected. What it keeps from the entity itself (such as
+/// its original colors, captured on the first frame) is not.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct SplashItemFade {
+    timer_wait: Timer,
+    timer_intro: Timer,
+    timer_on: Timer,
+    timer_fade: Timer,
+    intro_ease: EaseFunction,
+    fade_ease: EaseFunction,
+    fade_style: FadeOutStyle,
+    /// The alpha to apply on this frame, if it changed
+    #[reflect(ignore)]
+    alpha: Option<f32>,
+    /// The alpha computed from the phases, before any [`SplashItemHover`]
+    #[reflect(ignore)]
+    phase_alpha: f32,
+    /// Original color of each text section, captured on the first frame
+    #[reflect(ignore)]
+    text_colors: Option<Vec<Color>>,
+    /// Original color of the entity, captured on the first frame, if needed
+    #[reflect(ignore)]
+    base_color: Option<Color>,
+    /// Our own copy of the entity's material, created on the first frame
+    #[reflect(ignore)]
+    material: Option<Handle<ColorMaterial>>,
+    /// Colors to go from and to, during the intro phase
+    #[reflect(ignore)]
+    colors: Option<(Color, Color)>,
+    /// Alpha when "fully transparent"
+    min_alpha: f32,
+    /// Alpha when "fully opaque"
+    max_alpha: f32,
+    /// How many times to replay the intro, on, and fade phases
+    yoyo: Option<u32>,
+    /// How many of those cycles have been played to the end
+    cycles_done: u32,
+    /// Has this cycle been shown fully opaque? (for zero on and fade phases)
+    peaked: bool,
+    /// Do the timers count frames (in units of [`FADE_FRAME`]), instead of time?
+    frames: bool,
+    /// Stay in the on phase forever, until skipped?
+    hold: bool,
+    alpha_mode: SplashAlphaMode,
+    /// Sound to follow the playback of, instead of the splash screen clock
+    #[cfg(feature = "audio")]
+    #[reflect(ignore)]
+    audio_sync: Option<SplashAudioSync>,
+}
+
+/// Playback followed by a [`SplashItemFade`], see [`SplashItemFade::with_audio_sync`]
+#[cfg(feature = "audio")]
+struct SplashAudioSync {
+    sink: Handle<AudioSink>,
+    /// How fast the item advances on this frame, relative to the splash screen clock
+    rate: f32,
+    /// Still waiting for the sound to start playing?
+    waiting: bool,
+    /// How long we have been waiting for it
+    waited: Duration,
+}
+
+/// How long a [`SplashItemFade::with_audio_sync`] waits for its sound to start
+#[cfg(feature = "audio")]
+const AUDIO_SYNC_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Why [`SplashItemFade::keyframes`] rejected its times
+///
+/// Both variants hold the times that were given, in order of the arguments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplashKeyframesError {
+    /// Some of the times are negative, or not finite
+    Invalid([f32; 4]),
+    /// The times are not in order
+    OutOfOrder([f32; 4]),
+}
+
+impl fmt::Display for SplashKeyframesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SplashKeyframesError::Invalid(times) => {
+                write!(f, "SplashItemFade keyframe times must be finite and not negative: {:?}", times)
+            }
+            SplashKeyframesError::OutOfOrder(times) => {
+                write!(f, "SplashItemFade keyframe times must be in order: {:?}", times)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SplashKeyframesError {}
+
+/// How a [`SplashItemFade`] disappears at the end
+///
+/// See [`SplashItemFade::with_fade_out_style`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect_value(PartialEq)]
+pub enum FadeOutStyle {
+    /// Fade out gradually, over the fade phase, following `fade_ease`
+    #[default]
+    Fade,
+    /// Stay fully opaque during the fade phase, and disappear when it ends
+    ///
+    /// The item goes from fully opaque to fully transparent from one
+    /// frame to the next, without any frame in between.
+    Cut,
+}
+
+/// How a [`SplashItemFade`] applies its alpha to colors
+///
+/// See [`SplashItemFade::with_alpha_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect_value(PartialEq)]
+pub enum SplashAlphaMode {
+    /// Only change the alpha, for images with straight (unassociated) alpha
+    #[default]
+    Straight,
+    /// Also scale the color by the alpha, for images with premultiplied alpha
+    Premultiplied,
+}
+
+/// How much a frame-counting [`SplashItemFade`] advances on every frame
+///
+/// Their timers measure frames in this unit, instead of actual time.
+const FADE_FRAME: Duration = Duration::from_secs(1);
+
+impl Default for SplashItemFade {
+    fn default() -> Self {
+        Self::new(0.0, 0.0, 0.0, 0.0)
+    }
+}
+
+impl SplashItemFade {
+    /// Create a new fade item, with the duration of each phase in seconds
+    ///
+    /// Any of them can be zero, to skip that phase.
+    pub fn new(wait: f32, intro: f32, on: f32, fade: f32) -> Self {
+        Self {
+            timer_wait: Timer::from_seconds(wait, false),
+            timer_intro: Timer::from_seconds(intro, false),
+            timer_on: Timer::from_seconds(on, false),
+            timer_fade: Timer::from_seconds(fade, false),
+            intro_ease: EaseFunction::Linear,
+            fade_ease: EaseFunction::Linear,
+            fade_style: FadeOutStyle::Fade,
+            alpha: None,
+            phase_alpha: 0.0,
+            text_colors: None,
+            base_color: None,
+            material: None,
+            colors: None,
+            min_alpha: 0.0,
+            max_alpha: 1.0,
+            yoyo: None,
+            cycles_done: 0,
+            peaked: false,
+            frames: false,
+            hold: false,
+            alpha_mode: SplashAlphaMode::Straight,
+            #[cfg(feature = "audio")]
+            audio_sync: None,
+        }
+    }
+
+    /// Create a new fade item, from the times (in seconds) at which each phase starts
+    ///
+    /// An alternative to `new`, for lining up the item with something else
+    /// that happens at known times, such as a sound. The times are measured
+    /// from when the item starts playing: it starts appearing at
+    /// `appear_at`, is fully opaque from `full_at`, starts fading out at
+    /// `start_fade_at`, and is gone at `gone_at`. So it is the same as
+    /// `new(appear_at, full_at - appear_at, start_fade_at - full_at,
+    /// gone_at - start_fade_at)`.
+    ///
+    /// # Errors
+    ///
+    /// If the times are not in order (equal times are allowed, to skip a
+    /// phase), or any of them is negative or not finite.
+    pub fn keyframes(
+        appear_at: f32,
+        full_at: f32,
+        start_fade_at: f32,
+        gone_at: f32,
+    ) -> Result<Self, SplashKeyframesError> {
+        let times = [appear_at, full_at, start_fade_at, gone_at];
+        if !times.iter().all(|t| t.is_finite() && *t >= 0.0) {
+            return Err(SplashKeyframesError::Invalid(times));
+        }
+        if !times.windows(2).all(|pair| pair[0] <= pair[1]) {
+            return Err(SplashKeyframesError::OutOfOrder(times));
+        }
+        Ok(Self::new(
+            appear_at,
+            full_at - appear_at,
+            start_fade_at - full_at,
+            gone_at - start_fade_at,
+        ))
+    }
+
+    /// Create a new fade item, counting the number of frames of each phase
+    ///
+    /// Instead of measuring time, the item advances by exactly one frame
+    /// on every update of the app (while its [`SplashItemOrder`] group is
+    /// playing), ignoring Bevy's `Time`, the `clock` setting of the plugin,
+    /// [`SplashTestClock`], and [`SplashTimeScale`]. So it looks exactly
+    /// the same on every machine, every time, regardless of frame rate.
+    /// Useful for capturing trailers or tests. It still stops advancing
+    /// while [`SplashPaused`] exists.
+    ///
+    /// The `with_wait`, `with_intro`, `with_on`, and `with_fade` methods
+    /// also count frames, for such an item. The remaining time reported
+    /// in [`SplashState`] is estimated from the duration of the current frame.
+    pub fn frames(wait: u32, intro: u32, on: u32, fade: u32) -> Self {
+        let timer = |frames: u32| Timer::new(FADE_FRAME * frames, false);
+        Self {
+            timer_wait: timer(wait),
+            timer_intro: timer(intro),
+            timer_on: timer(on),
+            timer_fade: timer(fade),
+            frames: true,
+            ..Self::new(0.0, 0.0, 0.0, 0.0)
+        }
+    }
+
+    /// Convert a duration measured by the timers, to actual time
+    ///
+    /// For items counting frames, `frame` is the duration of one frame.
+    fn to_time(&self, duration: Duration, frame: Duration) -> Duration {
+        if self.frames {
+            frame.mul_f64(duration.as_secs_f64() / FADE_FRAME.as_secs_f64())
+        } else {
+            duration
+        }
+    }
+
+    /// Create a fade item that stays fully opaque, until the user skips
+    ///
+    /// For a final "press any key to continue" screen. The item waits and
+    /// fades in like with `new` (with the durations in seconds), and then
+    /// holds at full opacity indefinitely, instead of fading out. It never
+    /// finishes by itself, so the splash screen cannot complete while it
+    /// is displayed: it only ends when the user skips (or after its
+    /// `max_duration`). With [`SkipMode::Item`], skipping its group
+    /// finishes it, like any other item.
+    ///
+    /// A splash screen made only of such items has to be skipped to move
+    /// on, so make sure it is skippable. Yoyo has no effect on them, and
+    /// their progress is `1.0` as soon as they have faded in. While one is
+    /// displayed, [`SplashState::remaining`] is `None`.
+    pub fn until_skipped(wait: f32, intro: f32) -> Self {
+        Self {
+            hold: true,
+            ..Self::new(wait, intro, 0.0, 0.0)
+        }
+    }
+
+    /// A short reveal, 1.5 seconds in total
+    ///
+    /// No wait, 0.25s intro, 1s on, 0.25s fade.
+    pub fn quick() -> Self {
+        Self::new(0.0, 0.25, 1.0, 0.25)
+    }
+
+    /// A typical logo reveal, 3.5 seconds in total
+    ///
+    /// 0.25s wait, 0.75s intro, 1.75s on, 0.75s fade.
+    pub fn standard() -> Self {
+        Self::new(0.25, 0.75, 1.75, 0.75)
+    }
+
+    /// A slow, cinematic reveal, 8 seconds in total
+    ///
+    /// 1s wait, 2s intro, 3s on, 2s fade, easing in and out with
+    /// [`EaseFunction::SineInOut`].
+    pub fn dramatic() -> Self {
+        Self::new(1.0, 2.0, 3.0, 2.0)
+            .with_easing(EaseFunction::SineInOut)
+    }
+
+    /// Divide a single total duration between the phases
+    ///
+    /// No wait, 25% intro, 50% on, 25% fade. For example, 3 seconds
+    /// gives a 0.75s intro, 1.5s on, and 0.75s fade.
+    pub fn from_total(total: Duration) -> Self {
+        let total = total.as_secs_f32();
+        Self::new(0.0, total * 0.25, total * 0.5, total * 0.25)
+    }
+
+    /// Set the duration of the wait phase, in seconds
+    pub fn with_wait(mut self, secs: f32) -> Self {
+        self.timer_wait = Timer::from_seconds(secs, false);
+        self
+    }
+
+    /// Set the duration of the intro (fade in) phase, in seconds
+    pub fn with_intro(mut self, secs: f32) -> Self {
+        self.timer_intro = Timer::from_seconds(secs, false);
+        self
+    }
+
+    /// Set the duration of the on (fully visible) phase, in seconds
+    pub fn with_on(mut self, secs: f32) -> Self {
+        self.timer_on = Timer::from_seconds(secs, false);
+        self
+    }
+
+    /// Set the duration of the fade (fade out) phase, in seconds
+    pub fn with_fade(mut self, secs: f32) -> Self {
+        self.timer_fade = Timer::from_seconds(secs, false);
+        self
+    }
+
+    /// After fading out, replay the intro, on, and fade phases `count` times
+    ///
+    /// The item fades back in again, for a pulsing effect. The wait phase
+    /// only happens once, at the start. The item only counts as finished
+    /// after the last fade out. By default, the phases are played once.
+    pub fn with_yoyo(mut self, count: u32) -> Self {
+        self.yoyo = Some(count);
+        self
+    }
+
+    /// Get the overall progress of the item, in the `0.0..=1.0` range
+    ///
+    /// This is the time elapsed in all phases (and all yoyo cycles),
+    /// divided by their total duration.
+    pub fn progress(&self) -> f32 {
+        let total = self.timer_wait.duration() + self.cycle_duration() * self.cycles();
+        if total.is_zero() {
+            return 1.0;
+        }
+        let elapsed = self.timer_wait.elapsed()
+            + self.cycle_duration() * self.cycles_done
+            + self.timer_intro.elapsed()
+            + self.timer_on.elapsed()
+            + self.timer_fade.elapsed();
+        elapsed.as_secs_f32() / total.as_secs_f32()
+    }
+
+    /// How many times the intro, on, and fade phases are played
+    fn cycles(&self) -> u32 {
+        if self.hold {
+            // the first on phase never ends
+            return 1;
+        }
+        self.yoyo.unwrap_or(0).saturating_add(1)
+    }
+
+    /// Duration of one play of the intro, on, and fade phases
+    fn cycle_duration(&self) -> Duration {
+        self.timer_intro.duration() + self.timer_on.duration() + self.timer_fade.duration()
+    }
+
+    /// Advance through the phases, returning the new alpha (if it changed)
+    ///
+    /// Time left over from a phase carries over into the next one, so
+    /// zero-duration phases are skipped over within the same frame (except
+    /// that the frame on which the intro ends is kept, if nothing follows it).
+    /// Also returns whether the item has finished.
+    ///
+    /// The timers are ticked before the alpha is picked, so the frame on
+    /// which the fade phase finishes (however far past its end the delta
+    /// goes) sets exactly `min_alpha`, and no later frame changes it.
+    fn advance(&mut self, delta: Duration) -> (Option<f32>, bool) {
+        if self.timer_fade.finished() {
+            return (None, true);
+        }
+        let mut delta = delta;
+        loop {
+            if let Some(alpha) = self.advance_cycle(&mut delta) {
+                return (Some(self.scale_alpha(alpha)), false);
+            }
+            if self.cycles_done + 1 >= self.cycles() {
+                break;
+            }
+            if self.cycle_duration().is_zero() {
+                // nothing to replay; don't spin through the remaining cycles
+                self.cycles_done = self.cycles() - 1;
+                break;
+            }
+            // yoyo back into the intro phase
+            self.cycles_done += 1;
+            self.peaked = false;
+            self.timer_intro.reset();
+            self.timer_on.reset();
+            self.timer_fade.reset();
+        }
+        // everything finished on this frame: land exactly on the end value,
+        // as there will be no more updates to the alpha after this one
+        (Some(self.min_alpha), true)
+    }
+
+    /// Advance through the phases once, consuming `delta`
+    ///
+    /// Returns the unscaled alpha, or `None` if all phases have finished.
+    fn advance_cycle(&mut self, delta: &mut Duration) -> Option<f32> {
+        for phase in 0..4 {
+            if phase == 2 && self.hold {
+                // fully opaque, until skipped
+                return Some(1.0);
+            }
+            if phase == 2 && !self.peaked && self.timer_on.duration().is_zero() && self.timer_fade.duration().is_zero() {
+                // with no on or fade phase, the item would vanish as soon as
+                // the intro ends: show it fully opaque for one frame first
+                self.peaked = true;
+                return Some(1.0);
+            }
+            let timer = match phase {
+                0 => &mut self.timer_wait,
+                1 => &mut self.timer_intro,
+                2 => &mut self.timer_on,
+                _ => &mut self.timer_fade,
+            };
+            if timer.finished() {
+                continue;
+            }
+            let left = timer.duration().saturating_sub(timer.elapsed());
+            // (this also finishes zero-duration timers, even if `delta` is zero)
+            timer.tick(*delta);
+            if !timer.finished() {
+                let t = timer.percent();
+                return Some(match phase {
+                    0 => 0.0,
+                    1 => self.intro_ease.apply(t),
+                    2 => 1.0,
+                    _ => match self.fade_style {
+                        FadeOutStyle::Fade => 1.0 - self.fade_ease.apply(t),
+                        FadeOutStyle::Cut => 1.0,
+                    },
+                });
+            }
+            *delta = delta.saturating_sub(left);
+        }
+        None
+    }
+
+    /// Map alpha from the `0.0..=1.0` range into `min_alpha..=max_alpha`
+    fn scale_alpha(&self, alpha: f32) -> f32 {
+        self.min_alpha + (self.max_alpha - self.min_alpha) * alpha
+    }
+
+    /// Never fade out below this alpha
+    ///
+    /// The alpha of the whole animation is scaled to the range between
+    /// this and `max_alpha`, so the item starts (during the wait phase)
+    /// and ends at this alpha, instead of being fully transparent. Useful
+    /// for a watermark that stays faintly visible. The default is `0.0`.
+    ///
+    /// Items waiting for their [`SplashItemOrder`] group to start, or for
+    /// the item they follow ([`SplashItemFollow`]), are still fully
+    /// transparent.
+    pub fn with_min_alpha(mut self, alpha: f32) -> Self {
+        self.min_alpha = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Never fade in above this alpha
+    ///
+    /// The alpha of the whole animation is scaled to the range between
+    /// `min_alpha` and this, so the item only gets this opaque during the
+    /// on phase. The default is `1.0`.
+    pub fn with_max_alpha(mut self, alpha: f32) -> Self {
+        self.max_alpha = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set how the alpha is applied to the item's color
+    ///
+    /// By default ([`SplashAlphaMode::Straight`]), only the alpha of the
+    /// color is changed, which is correct for most images. The colors of
+    /// images with premultiplied alpha have already been multiplied by
+    /// their alpha, so fading only the alpha makes their semi-transparent
+    /// edges look too dark or bright while fading. With
+    /// [`SplashAlphaMode::Premultiplied`], the red, green, and blue of the
+    /// color are also multiplied by the alpha (in linear color space),
+    /// starting from the color the item had on the first frame.
+    pub fn with_alpha_mode(mut self, mode: SplashAlphaMode) -> Self {
+        self.alpha_mode = mode;
+        self
+    }
+
+    /// Set how the item disappears at the end, see [`FadeOutStyle`]
+    ///
+    /// With [`FadeOutStyle::Cut`], the item is fully opaque until the end
+    /// of the fade phase, and fully transparent (`min_alpha`) from the
+    /// frame on which it ends, even if that frame goes past the end. So
+    /// the item cuts out at the time when it would have been gone with a
+    /// fade. The default is [`FadeOutStyle::Fade`].
+    pub fn with_fade_out_style(mut self, style: FadeOutStyle) -> Self {
+        self.fade_style = style;
+        self
+    }
+
+    /// Follow the playback of a sound, instead of the splash screen clock
+    ///
+    /// Pass the sink of a sound you play for the item (such as
+    /// `sinks.get_handle(audio.play(sound))`), and set the phases to line
+    /// up with it (see `keyframes`). The item then waits for the sound to
+    /// actually start playing (sounds start a few frames after `play`, or
+    /// later if they still have to load), freezes while the sound is
+    /// paused, and follows its speed. So, if the phases add up to the
+    /// length of the sound, the item finishes together with it.
+    ///
+    /// Bevy 0.8 does not tell the playback position of a sound, nor its
+    /// length, so this follows the playback from when the sink appears,
+    /// not the position itself. If the sound has not started within two
+    /// seconds (of splash screen time), such as if it failed to load or
+    /// there is no audio device, the item falls back to the splash screen
+    /// clock. It also still freezes with [`SplashPaused`], which does not
+    /// pause your sound. Has no effect on items counting frames.
+    ///
+    /// Requires the `audio` cargo feature.
+    #[cfg(feature = "audio")]
+    pub fn with_audio_sync(mut self, sink: Handle<AudioSink>) -> Self {
+        self.audio_sync = Some(SplashAudioSync {
+            sink,
+            rate: 0.0,
+            waiting: true,
+            waited: Duration::ZERO,
+        });
+        self
+    }
+
+    /// Check on the sound followed by `with_audio_sync`, for this frame
+    #[cfg(feature = "audio")]
+    fn update_audio_sync(&mut self, sinks: Option<&Assets<AudioSink>>, delta: Duration) {
+        let sync = match &mut self.audio_sync {
+            Some(sync) => sync,
+            None => return,
+        };
+        let sink = sinks.and_then(|sinks| sinks.get(&sync.sink));
+        sync.rate = match sink {
+            Some(sink) => {
+                sync.waiting = false;
+                if sink.is_paused() { 0.0 } else { sink.speed() }
+            }
+            None if !sync.waiting => 1.0,
+            None if sync.waited >= AUDIO_SYNC_TIMEOUT => {
+                warn!(
+                    "The sound of a SplashItemFade did not start playing within {:?}, \
+                     falling back to the splash screen clock.",
+                    AUDIO_SYNC_TIMEOUT
+                );
+                sync.waiting = false;
+                1.0
+            }
+            None => {
+                sync.waited += delta;
+                0.0
+            }
+        };
+    }
+
+    /// How much the item advances on this frame, out of `delta`
+    fn synced_delta(&self, delta: Duration) -> Duration {
+        #[cfg(feature = "audio")]
+        if let Some(sync) = &self.audio_sync {
+            return if sync.rate.is_finite() && sync.rate > 0.0 {
+                delta.mul_f32(sync.rate)
+            } else {
+                Duration::ZERO
+            };
+        }
+        delta
+    }
+
+    /// The color to premultiply, if using [`SplashAlphaMode::Premultiplied`]
+    ///
+    /// Captures `current` on the first call, and returns that from then on.
+    fn premultiply_base(&mut self, current: Color) -> Option<Color> {
+        match self.alpha_mode {
+            SplashAlphaMode::Straight => None,
+            SplashAlphaMode::Premultiplied => Some(*self.base_color.get_or_insert(current)),
+        }
+    }
+
+    /// Has the intro phase started (the wait is over), at least once?
+    fn intro_started(&self) -> bool {
+        self.cycles_done > 0 || self.timer_wait.finished()
+    }
+
+    /// Has the intro phase been played through at least once?
+    fn intro_finished(&self) -> bool {
+        self.cycles_done > 0 || self.timer_intro.finished()
+    }
+
+    /// Time left, until the intro phase has been played through
+    fn intro_remaining(&self) -> Duration {
+        if self.intro_finished() {
+            return Duration::ZERO;
+        }
+        [&self.timer_wait, &self.timer_intro]
+            .iter()
+            .map(|timer| timer.duration().saturating_sub(timer.elapsed()))
+            .sum()
+    }
+
+    /// Name of the phase the item is in, for logging
+    fn phase_name(&self) -> &'static str {
+        if self.timer_fade.finished() {
+            "finished"
+        } else if self.timer_on.finished() {
+            "fade"
+        } else if self.timer_intro.finished() {
+            "on"
+        } else if self.timer_wait.finished() {
+            "intro"
+        } else {
+            "wait"
+        }
+    }
+
+    /// Limit `delta`, so the item does not get past its wait phase while its image is `loading`
+    ///
+    /// Otherwise, the image would pop in after the intro has started.
+    fn hold_while_loading(&self, loading: bool, delta: Duration) -> Duration {
+        if loading {
+            delta.min(self.timer_wait.duration().saturating_sub(self.timer_wait.elapsed()))
+        } else {
+            delta
+        }
+    }
+
+    /// Finish all phases (and yoyo cycles) immediately
+    fn finish(&mut self) {
+        self.cycles_done = self.cycles() - 1;
+        timer_finish(&mut self.timer_wait);
+        timer_finish(&mut self.timer_intro);
+        timer_finish(&mut self.timer_on);
+        timer_finish(&mut self.timer_fade);
+    }
+
+    /// Total time left, until all four phases (and yoyo cycles) have completed
+    ///
+    /// `None` while held by `until_skipped`, as it only ends when skipped.
+    fn remaining(&self) -> Option<Duration> {
+        if self.hold && !self.timer_fade.finished() {
+            return None;
+        }
+        let left: Duration = [&self.timer_wait, &self.timer_intro, &self.timer_on, &self.timer_fade]
+            .iter()
+            .map(|timer| timer.duration().saturating_sub(timer.elapsed()))
+            .sum();
+        Some(left + self.cycle_duration() * (self.cycles() - 1 - self.cycles_done))
+    }
+
+    /// Also change color, from `from` to `to`, during the intro phase
+    ///
+    /// The item is tinted with `from` during the wait phase, and with
+    /// `to` during the on and fade phases. All four components (including
+    /// alpha) are interpolated, following `intro_ease`, and the alpha of
+    /// the fade is then applied on top.
+    ///
+    /// Without this, only the alpha is changed, and the item keeps
+    /// whatever color it was spawned with.
+    pub fn with_color(mut self, from: Color, to: Color) -> Self {
+        self.colors = Some((from, to));
+        self
+    }
+
+    /// The color to apply on this frame, if using `with_color`
+    fn tint(&self) -> Option<Color> {
+        let (from, to) = self.colors?;
+        let t = if self.timer_intro.finished() {
+            1.0
+        } else if self.timer_intro.duration().is_zero() {
+            // not started yet
+            0.0
+        } else {
+            self.timer_intro.percent()
+        };
+        let t = self.intro_ease.apply(t);
+        Some(Vec4::from(from).lerp(Vec4::from(to), t).into())
+    }
+
+    /// Use the given easing curve for both fading in and out
+    pub fn with_easing(mut self, easing: EaseFunction) -> Self {
+        self.intro_ease = easing;
+        self.fade_ease = easing;
+        self
+    }
+
+    /// Use the given easing curve for fading in (the intro phase)
+    pub fn with_intro_ease(mut self, easing: EaseFunction) -> Self {
+        self.intro_ease = easing;
+        self
+    }
+
+    /// Use the given easing curve for fading out (the fade phase)
+    pub fn with_fade_ease(mut self, easing: EaseFunction) -> Self {
+        self.fade_ease = easing;
+        self
+    }
+
+    /// Set the duration of the wait phase to the next random one from `stagger`
+    ///
+    /// Use the same [`SplashStagger`] for a whole row of items, so that
+    /// they start fading in at slightly different times.
+    pub fn with_stagger(self, stagger: &mut SplashStagger) -> Self {
+        let wait = stagger.next_wait();
+        self.with_wait(wait)
+    }
+}
+
+/// Random wait durations, for giving a group of items a slight stagger
+///
+/// Apply it to each item with [`SplashItemFade::with_stagger`]. Every
+/// item gets a wait phase somewhere in the given range, in seconds.
+///
+/// The randomness comes from a small built-in generator (SplitMix64),
+/// seeded only from the `seed` you give it: nothing is read from the
+/// system. The same seed always gives the same waits, in the same order,
+/// on every platform, so the pattern is reproducible. For a different
+/// pattern every time the game runs, seed it from something that changes,
+/// such as the current time.
+#[derive(Debug, Clone)]
+pub struct SplashStagger {
+    state: u64,
+    wait: Range<f32>,
+}
+
+impl SplashStagger {
+    /// Create a new stagger, with waits in the `wait` range (in seconds)
+    pub fn new(seed: u64, wait: Range<f32>) -> Self {
+        Self {
+            state: seed,
+            wait,
+        }
+    }
+
+    /// Get the next random wait, in seconds
+    pub fn next_wait(&mut self) -> f32 {
+        // SplitMix64
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut x = self.state;
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        x ^= x >> 31;
+        // the top 24 bits, as a fraction in `0.0..1.0`
+        let fraction = (x >> 40) as f32 / (1u64 << 24) as f32;
+        let wait = self.wait.start + (self.wait.end - self.wait.start) * fraction;
+        // never negative, even with a weird range
+        wait.max(0.0)
+    }
+}
+
+/// A fading sprite, ready to be used as a splash screen item
+///
+/// Combines a [`SpriteBundle`], [`SplashItemFade`], and [`SplashCleanup`],
+/// so a fading logo can be spawned in one go:
+/// `commands.spawn_bundle(SplashBundle::new(texture, SplashItemFade::standard()))`.
+///
+/// The sprite is at the origin (the center of the screen, with the
+/// camera spawned by the plugin), with its center as the anchor, and its
+/// image at its natural size. Change `sprite.transform` to move it, or
+/// insert [`SplashAnchor`] or [`SplashItemFit`]. To also add it to the
+/// [`SplashRoot`], use [`SplashCommandsExt::spawn_splash_item`] instead
+/// of `spawn_bundle`.
+#[derive(Bundle)]
+pub struct SplashBundle {
+    #[bundle]
+    pub sprite: SpriteBundle,
+    pub fade: SplashItemFade,
+    pub cleanup: SplashCleanup,
+}
+
+impl SplashBundle {
+    /// Display the given image, with the given fade
+    pub fn new(texture: Handle<Image>, fade: SplashItemFade) -> Self {
+        Self {
+            sprite: SpriteBundle {
+                texture,
+                ..Default::default()
+            },
+            fade,
+            cleanup: SplashCleanup,
+        }
+    }
+}
+
+/// A fading UI image, ready to be used as a splash screen item
+///
+/// Like [`SplashBundle`], but with an [`ImageBundle`]. The node is
+/// placed by the UI layout, like any other, using the default [`Style`]:
+/// at the top left of its parent (or of the window), at the natural size
+/// of its image. Change `image.style` to position it. Do not spawn it
+/// with [`SplashCommandsExt::spawn_splash_item`], as UI nodes can only
+/// be children of other UI nodes.
+#[derive(Bundle)]
+pub struct SplashUiBundle {
+    #[bundle]
+    pub image: ImageBundle,
+    pub fade: SplashItemFade,
+    pub cleanup: SplashCleanup,
+}
+
+impl SplashUiBundle {
+    /// Display the given image, with the given fade
+    pub fn new(image: Handle<Image>, fade: SplashItemFade) -> Self {
+        Self {
+            image: ImageBundle {
+                image: UiImage(image),
+                ..Default::default()
+            },
+            fade,
+            cleanup: SplashCleanup,
+        }
+    }
+}
+
+/// Fading 2D text, ready to be used as a splash screen item
+///
+/// Like [`SplashBundle`], but with a [`Text2dBundle`]. The text is at
+/// the origin, using the alignment of `text` to decide which point of
+/// the text is there (use [`TextAlignment::CENTER`] to center it). Every section
+/// of the text is faded, keeping its own color. For text in right-to-left
+/// languages, see [`SplashCommandsExt::spawn_splash_text`].
+#[derive(Bundle)]
+pub struct SplashTextBundle {
+    #[bundle]
+    pub text: Text2dBundle,
+    pub fade: SplashItemFade,
+    pub cleanup: SplashCleanup,
+}
+
+impl SplashTextBundle {
+    /// Display the given text, with the given fade
+    pub fn new(text: Text, fade: SplashItemFade) -> Self {
+        Self {
+            text: Text2dBundle {
+                text,
+                ..Default::default()
+            },
+            fade,
+            cleanup: SplashCleanup,
+        }
+    }
+}
+
+/// The overall progress of a [`SplashItemFade`], in the `0.0..=1.0` range
+///
+/// Inserted automatically on every fade item, and updated every frame,
+/// with the value of [`SplashItemFade::progress`]. It is read-only. Read it to drive your
+/// own effects (such as a custom shader, or a UI element) from the fade.
+///
+/// Across the lifetime of the item, it goes from `0.0` to `1.0` at a
+/// constant rate, with each of the four phases taking up a share of the
+/// range proportional to its duration. For example, with a 1s wait, 1s
+/// intro, 2s on, and 1s fade, it is `0.2` by the end of the wait, `0.4`
+/// by the end of the intro, and `0.8` by the end of the on phase.
+///
+/// Only changes while the item is animating, so it can be used with
+/// change detection.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub struct SplashItemProgress(f32);
+
+impl SplashItemProgress {
+    /// Get the progress, in the `0.0..=1.0` range
+    pub fn get(&self) -> f32 {
+        self.0
+    }
+}
+
+/// Easing curves for animating splash items
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect_value(PartialEq)]
+pub enum EaseFunction {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    CubicInOut,
+    SineInOut,
+}
+
+impl EaseFunction {
+    /// Map linear progress `t` (in the `0.0..=1.0` range) onto the curve
+    pub fn apply(self, t: f32) -> f32 {
+        use std::f32::consts::PI;
+
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            EaseFunction::Linear => t,
+            EaseFunction::QuadIn => t * t,
+            EaseFunction::QuadOut => t * (2.0 - t),
+            EaseFunction::CubicInOut => if t < 0.5 {
+                4.0 * t * t * t
+            } else {
+                let u = 2.0 * t - 2.0;
+                0.5 * u * u * u + 1.0
+            },
+            EaseFunction::SineInOut => 0.5 - 0.5 * (PI * t).cos(),
+        }
+    }
+}
+
+/// Override the state to transition to, when the splash screen ends
+///
+/// Insert this on any of your splash screen entities, to go to a
+/// different state than the `next` configured in [`SplashPlugin`].
+///
+/// If multiple entities have this component, the one with the
+/// lowest entity id wins.
+///
+/// Has no effect with [`SplashProgressPlugin`], which does not
+/// perform the state transition itself.
+#[derive(Component)]
+pub struct SplashItemNext<S: StateData>(pub S);
+
+/// Make a splash item wait for other items, before it starts
+///
+/// Splash items are grouped by this number, and the groups play one
+/// after the other, in ascending order: the items in a group do not
+/// start until all items in all lower groups have finished. Items
+/// without this component are in group `0`.
+///
+/// Until their group starts, items stay at the beginning of their
+/// animation, and [`SplashItemFade`] items are fully transparent.
+///
+/// Use this to show multiple logos in sequence, in one splash screen.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct SplashItemOrder(pub u32);
+
+/// Mark a splash item as decoration, that does not hold up the splash screen
+///
+/// The item animates as usual, but the splash screen ends as soon
+/// as all other (required) items have finished, whether this one has
+/// finished or not. It is also not counted towards the progress or the
+/// remaining time reported in [`SplashState`].
+///
+/// In the same way, optional items do not hold up the next
+/// [`SplashItemOrder`] group from starting. A group made only of
+/// optional items starts together with the next group that has
+/// required items (or never, if there is none after it).
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct SplashItemOptional;
+
+/// Hold a [`SplashItemFade`] until another one has finished its intro phase
+///
+/// The item stays fully transparent, and its wait phase only starts,
+/// once the [`SplashItemFade`] on the given entity has finished fading
+/// in (on the same frame). For example, to fade in a second logo when
+/// the first one is fully visible. Items can follow items that follow
+/// other items, and so on.
+///
+/// This works within [`SplashItemOrder`] groups: the item also has to
+/// wait for its own group to start.
+///
+/// If the entity does not exist (or does not have a [`SplashItemFade`]),
+/// the item plays as if it did not have this component. If items follow
+/// each other in a cycle, a warning is logged, and they all play as if
+/// they did not have this component.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplashItemFollow(pub Entity);
+
+/// Display the overall `iyes_progress` progress as text
+///
+/// Insert this on an entity with [`Text`], during a splash screen
+/// made with [`SplashProgressPlugin`]. The first section of the text
+/// will be set to the progress percentage, formatted using `format`.
+#[cfg(feature = "iyes_progress")]
+#[derive(Component, Debug, Clone)]
+pub struct SplashProgressText {
+    /// Every `{}` in this string is replaced with the percentage
+    ///
+    /// Defaults to `"{}%"`. The percentage is rounded to a whole number.
+    pub format: String,
+}
+
+#[cfg(feature = "iyes_progress")]
+impl SplashProgressText {
+    /// Use a custom format string, instead of the default `"{}%"`
+    pub fn with_format(format: impl Into<String>) -> Self {
+        Self {
+            format: format.into(),
+        }
+    }
+}
+
+#[cfg(feature = "iyes_progress")]
+impl Default for SplashProgressText {
+    fn default() -> Self {
+        Self::with_format("{}%")
+    }
+}
+
+/// Splash screen item that simply stays visible for a fixed time
+///
+/// The splash screen will not complete until the timer has finished.
+/// It does not affect the appearance of the entity in any way.
+///
+/// To wait for several repetitions of the timer, also insert
+/// [`SplashItemRepeat`] on the same entity.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct SplashItemTimeout(pub Timer);
+
+impl SplashItemTimeout {
+    /// Create a new timeout item, lasting `secs` seconds
+    pub fn new(secs: f32) -> Self {
+        Self(Timer::from_seconds(secs, false))
+    }
+
+    /// Create a new timeout item, that waits for the given timer
+    pub fn from_timer(timer: Timer) -> Self {
+        Self(timer)
+    }
+}
+
+impl From<Timer> for SplashItemTimeout {
+    fn from(timer: Timer) -> Self {
+        Self(timer)
+    }
+}
+
+/// Makes a [`SplashItemTimeout`] wait for its timer to finish `count` times
+///
+/// Useful to match animations driven by something else, like a
+/// looping sprite-sheet animation. Use a repeating timer. If it is
+/// not repeating, it is reset every time it finishes, until it has
+/// finished `count` times.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct SplashItemRepeat {
+    pub count: u32,
+    /// How many times the timer has finished so far
+    completed: u32,
+}
+
+impl Default for SplashItemRepeat {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl SplashItemRepeat {
+    /// Wait for `count` repetitions of the timer
+    pub fn new(count: u32) -> Self {
+        Self {
+            count,
+            completed: 0,
+        }
+    }
+
+    /// Advance the timer, counting repetitions
+    fn tick(&mut self, timer: &mut Timer, delta: Duration) {
+        if self.finished() {
+            return;
+        }
+        if timer.duration().is_zero() {
+            // would finish infinitely many times
+            self.completed = self.count;
+            return;
+        }
+        timer.tick(delta);
+        self.completed += timer.times_finished_this_tick();
+        if timer.finished() && !timer.repeating() && !self.finished() {
+            timer.reset();
+        }
+    }
+
+    /// Finish all repetitions immediately
+    fn finish(&mut self, timer: &mut Timer) {
+        self.completed = self.count;
+        timer_finish(timer);
+    }
+
+    fn finished(&self) -> bool {
+        self.completed >= self.count
+    }
+
+    /// Overall progress, in the `0.0..=1.0` range
+    fn progress(&self, timer: &Timer) -> f32 {
+        if self.finished() {
+            1.0
+        } else {
+            (self.completed as f32 + timer_progress(timer)) / self.count as f32
+        }
+    }
+
+    /// Total time left, until all repetitions have finished
+    fn remaining(&self, timer: &Timer) -> Duration {
+        if self.finished() {
+            Duration::ZERO
+        } else {
+            let left = timer.duration().saturating_sub(timer.elapsed());
+            left + timer.duration() * (self.count - self.completed - 1)
+        }
+    }
+}
+
+/// Splash screen item that animates its scale
+///
+/// The entity's [`Transform`] scale is interpolated from
+/// `start_scale` to `end_scale`, over the duration of the timer.
+///
+/// Can be combined with [`SplashItemFade`] on the same entity.
+#[derive(Component)]
+pub struct SplashItemScale {
+    pub start_scale: Vec3,
+    pub end_scale: Vec3,
+    pub timer: Timer,
+}
+
+impl SplashItemScale {
+    /// Create a new scale animation, lasting `secs` seconds
+    pub fn new(start_scale: Vec3, end_scale: Vec3, secs: f32) -> Self {
+        Self {
+            start_scale,
+            end_scale,
+            timer: Timer::from_seconds(secs, false),
+        }
+    }
+}
+
+/// Splash screen item that animates its position
+///
+/// The entity's [`Transform`] translation is interpolated from
+/// `start` to `end`, over the duration of the timer. This overrides
+/// whatever translation the entity was spawned with.
+///
+/// Can be combined with [`SplashItemFade`] on the same entity.
+#[derive(Component)]
+pub struct SplashItemMove {
+    pub start: Vec3,
+    pub end: Vec3,
+    pub timer: Timer,
+}
+
+impl SplashItemMove {
+    /// Create a new move animation, lasting `secs` seconds
+    pub fn new(start: Vec3, end: Vec3, secs: f32) -> Self {
+        Self {
+            start,
+            end,
+            timer: Timer::from_seconds(secs, false),
+        }
+    }
+}
+
+/// Keep a splash screen entity positioned relative to the window
+///
+/// The entity's [`Transform`] translation is set to a point on the
+/// primary window (plus `offset`), when the splash screen starts, and
+/// again whenever the window is resized. Useful to keep logos framed
+/// nicely on different resolutions and aspect ratios.
+///
+/// Positions are computed in logical window pixels, assuming a 2D
+/// camera at the origin with the default projection (one world unit
+/// per pixel), like the one spawned by the plugin. The `z` coordinate
+/// is left unchanged.
+///
+/// Do not combine with [`SplashItemMove`] on the same entity.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SplashAnchor {
+    pub point: SplashAnchorPoint,
+    pub offset: Vec2,
+}
+
+/// Which point of the window a [`SplashAnchor`] is relative to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplashAnchorPoint {
+    /// The middle of the top edge of the window
+    TopCenter,
+    /// The center of the window
+    #[default]
+    Center,
+    /// The middle of the bottom edge of the window
+    BottomCenter,
+}
+
+impl SplashAnchor {
+    /// Anchor to the middle of the top edge of the window
+    pub fn top_center(offset: Vec2) -> Self {
+        Self {
+            point: SplashAnchorPoint::TopCenter,
+            offset,
+        }
+    }
+
+    /// Anchor to the center of the window
+    pub fn center(offset: Vec2) -> Self {
+        Self {
+            point: SplashAnchorPoint::Center,
+            offset,
+        }
+    }
+
+    /// Anchor to the middle of the bottom edge of the window
+    pub fn bottom_center(offset: Vec2) -> Self {
+        Self {
+            point: SplashAnchorPoint::BottomCenter,
+            offset,
+        }
+    }
+
+    /// Compute the position, for a window of the given (logical) height
+    fn position(&self, height: f32) -> Vec2 {
+        let point = match self.point {
+            SplashAnchorPoint::TopCenter => Vec2::new(0.0, height / 2.0),
+            SplashAnchorPoint::Center => Vec2::ZERO,
+            SplashAnchorPoint::BottomCenter => Vec2::new(0.0, -height / 2.0),
+        };
+        point + self.offset
+    }
+}
+
+/// Scale a sprite to fit the window, preserving its aspect ratio
+///
+/// The entity's [`Transform`] scale (`x` and `y`) is kept up to date
+/// with the primary window's size, and the size of the sprite: its
+/// `custom_size`, if set, or otherwise the size of its image. If the image
+/// has not loaded yet, the sprite is scaled as soon as it has.
+///
+/// Like [`SplashAnchor`], this assumes a 2D camera with the default
+/// projection (one world unit per logical window pixel), so it does not
+/// line up if you change the plugin's `camera_scaling`.
+///
+/// Do not combine with [`SplashItemScale`] on the same entity.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub enum SplashItemFit {
+    /// Scale to be as large as possible, while fitting inside the window
+    Contain,
+    /// Scale to be as small as possible, while covering the whole window
+    Cover,
+    /// Always use this scale, regardless of the window size
+    Fixed(f32),
+}
+
+impl SplashItemFit {
+    /// Compute the scale for a sprite of the given size
+    fn scale(&self, size: Vec2, window: Vec2) -> f32 {
+        let ratio = window / size;
+        match *self {
+            SplashItemFit::Contain => ratio.min_element(),
+            SplashItemFit::Cover => ratio.max_element(),
+            SplashItemFit::Fixed(scale) => scale,
+        }
+    }
+}
+
+/// Draw a 2D splash screen item above or below the others
+///
+/// Overlapping sprites (or text) at the same depth can be drawn in any
+/// order, and may even flicker. Give each one a layer, and items on
+/// higher layers are drawn on top: the entity's [`Transform`] translation
+/// `z` is set to the layer number, while `x` and `y` are left unchanged.
+/// For example, use layer `0` for a background image, and `1` for a
+/// logo in front of it.
+///
+/// This overrides the `z` that the entity was spawned with, even while
+/// a [`SplashItemMove`] is animating it. Entities without a layer keep
+/// their own `z`, so you can also mix the two: a layer is equivalent to
+/// spawning the entity at that `z`. With the camera spawned by the
+/// plugin, layers from `0` to `999` are visible. It has no effect on UI
+/// nodes, which are drawn in the order of the UI hierarchy.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SplashLayer(pub i32);
+
+/// Writing direction of splash screen text
+///
+/// See [`SplashCommandsExt::spawn_splash_text`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplashTextDirection {
+    /// Detect the direction from the text itself
+    ///
+    /// The first letter from a right-to-left script (such as Arabic or
+    /// Hebrew) or a left-to-right script (such as Latin) decides. Digits,
+    /// spaces, and punctuation are skipped over. Text with no letters at
+    /// all is left-to-right.
+    #[default]
+    Auto,
+    /// Left-to-right, such as English
+    LeftToRight,
+    /// Right-to-left, such as Arabic or Hebrew
+    RightToLeft,
+}
+
+impl SplashTextDirection {
+    /// The usual direction for the language of a locale
+    ///
+    /// Accepts language tags such as `"ar"`, `"he-IL"`, or `"en_US"`.
+    /// Languages written right-to-left give `RightToLeft`, and all other
+    /// (including unrecognized) ones give `LeftToRight`.
+    pub fn from_locale(locale: &str) -> Self {
+        let language = locale.split(['-', '_']).next().unwrap_or_default();
+        const RTL: &[&str] = &[
+            "ar", "arc", "ckb", "dv", "fa", "he", "iw", "ps", "sd", "ug", "ur", "yi",
+        ];
+        if RTL.iter().any(|rtl| language.eq_ignore_ascii_case(rtl)) {
+            SplashTextDirection::RightToLeft
+        } else {
+            SplashTextDirection::LeftToRight
+        }
+    }
+
+    /// Is this right-to-left, for the given text?
+    fn is_rtl(self, text: &str) -> bool {
+        match self {
+            SplashTextDirection::LeftToRight => false,
+            SplashTextDirection::RightToLeft => true,
+            SplashTextDirection::Auto => text
+                .chars()
+                .map(BidiClass::of)
+                .find(|class| matches!(class, BidiClass::L | BidiClass::R))
+                == Some(BidiClass::R),
+        }
+    }
+}
+
+/// Simplified Unicode bidi character types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BidiClass {
+    /// Left-to-right letters
+    L,
+    /// Right-to-left letters
+    R,
+    /// Digits
+    EN,
+    /// Everything else (spaces, punctuation, symbols)
+    N,
+}
+
+impl BidiClass {
+    fn of(c: char) -> Self {
+        match c as u32 {
+            // Hebrew, Arabic, Syriac, Thaana, NKo, Samaritan, Mandaic,
+            // and their presentation forms
+            0x0590..=0x05FF | 0x0600..=0x065F | 0x066A..=0x06EF | 0x06FA..=0x08FF
+            | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF => BidiClass::R,
+            // ASCII, Arabic-Indic, and extended Arabic-Indic digits
+            0x0030..=0x0039 | 0x0660..=0x0669 | 0x06F0..=0x06F9 => BidiClass::EN,
+            _ if c.is_alphabetic() => BidiClass::L,
+            _ => BidiClass::N,
+        }
+    }
+}
+
+/// Reorder a line of text from logical to visual (left-to-right) order
+///
+/// Bevy draws the characters of a text left-to-right, in the order they
+/// appear in the string, so right-to-left runs have to be reversed first.
+/// Follows the Unicode bidirectional algorithm, without explicit
+/// embeddings or isolates: digits stay left-to-right, matching brackets
+/// go with the text they enclose, neutral characters between two runs
+/// of the same direction take that direction (or the direction of the
+/// line otherwise), and brackets in right-to-left runs are mirrored.
+fn bidi_reorder_line(line: &str, rtl: bool) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let base = if rtl { BidiClass::R } else { BidiClass::L };
+    let mut classes: Vec<BidiClass> = chars.iter().map(|&c| BidiClass::of(c)).collect();
+    // digits after left-to-right letters (or at the start of a
+    // left-to-right line) are just left-to-right text
+    let mut last_strong = base;
+    for class in classes.iter_mut() {
+        match *class {
+            BidiClass::L | BidiClass::R => last_strong = *class,
+            BidiClass::EN if last_strong == BidiClass::L => *class = BidiClass::L,
+            _ => {}
+        }
+    }
+    // digits count as right-to-left, when resolving neutrals
+    let strong = |class: BidiClass| match class {
+        BidiClass::L => Some(BidiClass::L),
+        BidiClass::R | BidiClass::EN => Some(BidiClass::R),
+        BidiClass::N => None,
+    };
+    // matching brackets take the direction of the line, if there is any
+    // text in that direction between them, or otherwise of the text
+    // between them, if the text before them agrees
+    let mut open: Vec<(usize, char)> = Vec::new();
+    let mut pairs = Vec::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let opening = match c {
+            '(' | '[' | '{' => {
+                open.push((i, c));
+                continue;
+            }
+            ')' => '(',
+            ']' => '[',
+            '}' => '{',
+            _ => continue,
+        };
+        if let Some(depth) = open.iter().rposition(|&(_, c)| c == opening) {
+            pairs.push((open[depth].0, i));
+            open.truncate(depth);
+        }
+    }
+    pairs.sort_unstable();
+    for (start, end) in pairs {
+        let inside: Vec<BidiClass> = classes[start + 1..end].iter().filter_map(|&c| strong(c)).collect();
+        let class = if inside.contains(&base) {
+            base
+        } else if let Some(&other) = inside.first() {
+            let before = classes[..start].iter().rev().find_map(|&c| strong(c)).unwrap_or(base);
+            if before == other { other } else { base }
+        } else {
+            continue;
+        };
+        classes[start] = class;
+        classes[end] = class;
+    }
+    // neutrals take the direction of their surroundings, if they agree
+    let mut i = 0;
+    while i < classes.len() {
+        if classes[i] != BidiClass::N {
+            i += 1;
+            continue;
+        }
+        let end = (i..classes.len())
+            .find(|&j| classes[j] != BidiClass::N)
+            .unwrap_or(classes.len());
+        let before = i.checked_sub(1).and_then(|j| strong(classes[j])).unwrap_or(base);
+        let after = classes.get(end).and_then(|&class| strong(class)).unwrap_or(base);
+        // trailing whitespace always goes with the line
+        let class = if before == after && end < classes.len() { before } else { base };
+        classes[i..end].fill(class);
+        i = end;
+    }
+    let levels: Vec<u8> = classes
+        .iter()
+        .map(|class| match (rtl, class) {
+            (false, BidiClass::L) => 0,
+            (false, BidiClass::R) | (true, BidiClass::R) => 1,
+            _ => 2,
+        })
+        .collect();
+    let mirror = |c: char| match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        c => c,
+    };
+    let mut visual: Vec<(char, u8)> = chars
+        .iter()
+        .zip(levels)
+        .map(|(&c, level)| (if level % 2 == 1 { mirror(c) } else { c }, level))
+        .collect();
+    // reverse every run at each level and above, from the highest level
+    // down to the lowest odd level
+    for level in (1..=2).rev() {
+        let mut i = 0;
+        while i < visual.len() {
+            if visual[i].1 < level {
+                i += 1;
+                continue;
+            }
+            let end = (i..visual.len())
+                .find(|&j| visual[j].1 < level)
+                .unwrap_or(visual.len());
+            visual[i..end].reverse();
+            i = end;
+        }
+    }
+    visual.into_iter().map(|(c, _)| c).collect()
+}
+
+/// Splash screen item that pulses its alpha, like a blinking prompt
+///
+/// Works with sprites ([`Sprite`]) and UI nodes ([`UiColor`]).
+/// The alpha follows a smooth wave, starting and ending every
+/// `period` fully opaque, and fading out completely in the middle.
+///
+/// If `cycles` is set, the item finishes after blinking that many
+/// times, and stays fully opaque afterwards. Otherwise, it keeps
+/// blinking for as long as the splash screen runs, and does not
+/// prevent the splash screen from completing. A splash screen with only
+/// such items never completes on its own, it can only be skipped.
+///
+/// Do not combine with [`SplashItemFade`] on the same entity.
+#[derive(Component)]
+pub struct SplashItemBlink {
+    pub period: Duration,
+    pub cycles: Option<u32>,
+    elapsed: Duration,
+}
+
+impl SplashItemBlink {
+    /// Create a new item blinking forever, once every `secs` seconds
+    pub fn new(secs: f32) -> Self {
+        Self {
+            period: Duration::from_secs_f32(secs),
+            cycles: None,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Stop blinking after this many blinks
+    pub fn with_cycles(mut self, cycles: u32) -> Self {
+        self.cycles = Some(cycles);
+        self
+    }
+
+    /// How long until the item finishes, if it ever does
+    fn duration(&self) -> Option<Duration> {
+        self.cycles.map(|cycles| self.period * cycles)
+    }
+
+    fn finished(&self) -> bool {
+        self.duration().is_some_and(|duration| self.elapsed >= duration)
+    }
+
+    /// The alpha value for the current point in time
+    fn alpha(&self) -> f32 {
+        if self.finished() || self.period.is_zero() {
+            return 1.0;
+        }
+        let phase = self.elapsed.as_secs_f32() / self.period.as_secs_f32();
+        0.5 + 0.5 * (phase * std::f32::consts::TAU).cos()
+    }
+}
+
+/// Make a splash screen item react to the mouse cursor
+///
+/// Purely cosmetic, for playful splash screens: the closer the cursor
+/// is to the item, the more opaque and the larger it gets. At a distance
+/// of `radius` or more, its alpha is multiplied by `alpha`, and it has
+/// its normal size. With the cursor over it, it is as opaque as usual,
+/// and its scale is multiplied by `scale`. In between, both change
+/// smoothly. By default, neither changes: set them with `with_alpha`
+/// and `with_scale`.
+///
+/// The alpha is applied on top of [`SplashItemFade`], which is required
+/// on the same entity for it to have any effect. The scale works on its
+/// own, relative to the scale the entity has on the first frame, but do
+/// not combine it with [`SplashItemScale`] or [`SplashItemFit`] on the
+/// same entity.
+///
+/// The distance is measured from the edges of sprites (of their
+/// `custom_size`, or the size of their image), or from the entity's
+/// position for anything else, on the primary window. Like
+/// [`SplashAnchor`], this assumes a 2D camera at the origin with the
+/// default projection, like the one spawned by the plugin.
+///
+/// It never counts as unfinished, so it does not change when the splash
+/// screen completes, or what it reports to `iyes_progress`.
+#[derive(Component, Debug, Clone)]
+pub struct SplashItemHover {
+    pub radius: f32,
+    pub alpha: f32,
+    pub scale: f32,
+    /// How close the cursor is, from `0.0` (far) to `1.0` (over the item)
+    amount: f32,
+    /// The scale of the transform without the hover, captured on the first frame
+    base_scale: Option<Vec2>,
+    /// The scale multiplier currently applied to the transform
+    applied_scale: f32,
+}
+
+impl SplashItemHover {
+    /// React to the cursor within `radius` world units of the item
+    pub fn new(radius: f32) -> Self {
+        Self {
+            radius,
+            alpha: 1.0,
+            scale: 1.0,
+            amount: 0.0,
+            base_scale: None,
+            applied_scale: 1.0,
+        }
+    }
+
+    /// Multiply the alpha by this, while the cursor is far away
+    pub fn with_alpha(mut self, alpha: f32) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Multiply the scale by this, while the cursor is over the item
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// How close the cursor is, from `0.0` (far away) to `1.0` (over the item)
+    pub fn amount(&self) -> f32 {
+        self.amount
+    }
+
+    fn alpha_factor(&self) -> f32 {
+        self.alpha + (1.0 - self.alpha) * self.amount
+    }
+
+    fn scale_factor(&self) -> f32 {
+        1.0 + (self.scale - 1.0) * self.amount
+    }
+
+    /// The new scale of a transform currently scaled by `current`, if it changed
+    fn rescale(&mut self, current: Vec3) -> Option<Vec3> {
+        let base = *self.base_scale.get_or_insert(current.truncate());
+        let scale = self.scale_factor();
+        if self.applied_scale == scale {
+            return None;
+        }
+        self.applied_scale = scale;
+        // (from the base, so that a zero scale can still grow back)
+        Some((base * scale).extend(current.z))
+    }
+}
+
+/// Splash screen item that plays a sound
+///
+/// The sound starts playing as soon as the item is spawned. The item
+/// is finished when the timer completes, which should be set to the
+/// length of the sound (Bevy cannot tell us when playback ends).
+///
+/// If the user skips the splash screen, the sound is stopped.
+///
+/// Requires the `audio` cargo feature.
+#[cfg(feature = "audio")]
+#[derive(Component)]
+pub struct SplashItemAudio {
+    pub source: Handle<AudioSource>,
+    pub timer: Timer,
+    sink: Option<Handle<AudioSink>>,
+}
+
+#[cfg(feature = "audio")]
+impl SplashItemAudio {
+    /// Create a new audio item, lasting `secs` seconds
+    pub fn new(source: Handle<AudioSource>, secs: f32) -> Self {
+        Self {
+            source,
+            timer: Timer::from_seconds(secs, false),
+            sink: None,
+        }
+    }
+
+    fn stop(&self, sinks: &Assets<AudioSink>) {
+        if let Some(sink) = self.sink.as_ref().and_then(|h| sinks.get(h)) {
+            sink.stop();
+        }
+    }
+}
+
+/// Music to fade out when the splash screen ends
+///
+/// Insert it with the sink of the music you play during the splash
+/// screen (such as `sinks.get_handle(audio.play(music))`), and configure
+/// the fade with `.music_fade_out(...)` on the splash screen plugin. It
+/// is removed when the fade starts, so insert it again for the music of
+/// the next splash screen.
+///
+/// Requires the `audio` cargo feature.
+#[cfg(feature = "audio")]
+#[derive(Debug, Clone)]
+pub struct SplashMusic(pub Handle<AudioSink>);
+
+/// Music being faded out, by `.music_fade_out(...)`
+#[cfg(feature = "audio")]
+struct SplashMusicFade {
+    sink: Handle<AudioSink>,
+    /// The volume as of the start of the fade
+    volume: f32,
+    timer: Timer,
+}
+
+/// All music fades in progress (kept across splash screens)
+#[cfg(feature = "audio")]
+struct SplashMusicFading(Vec<SplashMusicFade>);
+
+/// Start fading out the [`SplashMusic`], when the splash screen ends (or on exit)
+#[cfg(feature = "audio")]
+#[allow(clippy::type_complexity)]
+fn splash_music_fade_start(
+    on_exit: bool,
+) -> impl FnMut(
+    Commands,
+    Option<Res<SplashMusic>>,
+    Option<Res<SplashEnded>>,
+    Res<SplashConfig>,
+    Res<Assets<AudioSink>>,
+    ResMut<SplashMusicFading>,
+) {
+    move |mut commands, music, ended, config, sinks, mut fading| {
+        let ended = on_exit || ended.is_some_and(|ended| ended.0.is_some());
+        if let (Some(music), Some(duration), true) = (music, config.music_fade_out, ended) {
+            let volume = sinks.get(&music.0).map(|sink| sink.volume()).unwrap_or(1.0);
+            fading.0.push(SplashMusicFade {
+                sink: music.0.clone(),
+                volume,
+                timer: Timer::new(duration, false),
+            });
+            commands.remove_resource::<SplashMusic>();
+        }
+    }
+}
+
+#[cfg(feature = "audio")]
+fn splash_music_fade(
+    mut fading: ResMut<SplashMusicFading>,
+    sinks: Res<Assets<AudioSink>>,
+    test_clock: Option<Res<SplashTestClock>>,
+    t: Res<Time>,
+) {
+    if fading.0.is_empty() {
+        return;
+    }
+    // the splash screen (and its clock) may be gone already
+    let delta = test_clock.map(|clock| clock.0).unwrap_or_else(|| t.delta());
+    fading.0.retain_mut(|fade| {
+        let sink = match sinks.get(&fade.sink) {
+            Some(sink) => sink,
+            None => return false,
+        };
+        fade.timer.tick(delta);
+        if fade.timer.finished() {
+            sink.stop();
+            return false;
+        }
+        sink.set_volume(fade.volume * (1.0 - timer_progress(&fade.timer)));
+        true
+    });
+}
+
+/// Something with a color, whose alpha splash items can animate
+trait SplashColorTarget {
+    fn set_alpha(&mut self, alpha: f32);
+    fn get_color(&self) -> Color;
+    fn set_color(&mut self, color: Color);
+
+    /// Apply the alpha computed by a [`SplashItemFade`], and its tint, if any
+    ///
+    /// If `premultiply` is set (to the original color, for when there
+    /// is no tint), the color is also multiplied by `alpha`.
+    fn apply_fade(&mut self, alpha: f32, tint: Option<Color>, premultiply: Option<Color>) {
+        let mut color = match (tint, premultiply) {
+            (Some(mut color), _) => {
+                color.set_a(color.a() * alpha);
+                color
+            }
+            (None, Some(mut color)) => {
+                color.set_a(alpha);
+                color
+            }
+            (None, None) => {
+                self.set_alpha(alpha);
+                return;
+            }
+        };
+        if premultiply.is_some() {
+            // (`Color::r()` and friends return sRGB components, even for linear colors)
+            let [r, g, b, a] = color.as_linear_rgba_f32();
+            color = Color::rgba_linear(r * alpha, g * alpha, b * alpha, a);
+        }
+        self.set_color(color);
+    }
+}
+
+impl SplashColorTarget for Sprite {
+    fn get_color(&self) -> Color {
+        self.color
+    }
+    fn set_alpha(&mut self, alpha: f32) {
+        self.color.set_a(alpha);
+    }
+    fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+}
+
+impl SplashColorTarget for UiColor {
+    fn get_color(&self) -> Color {
+        self.0
+    }
+    fn set_alpha(&mut self, alpha: f32) {
+        self.0.set_a(alpha);
+    }
+    fn set_color(&mut self, color: Color) {
+        self.0 = color;
+    }
+}
+
+impl SplashColorTarget for TextSection {
+    fn get_color(&self) -> Color {
+        self.style.color
+    }
+    fn set_alpha(&mut self, alpha: f32) {
+        self.style.color.set_a(alpha);
+    }
+    fn set_color(&mut self, color: Color) {
+        self.style.color = color;
+    }
+}
+
+impl SplashColorTarget for ColorMaterial {
+    fn get_color(&self) -> Color {
+        self.color
+    }
+    fn set_alpha(&mut self, alpha: f32) {
+        self.color.set_a(alpha);
+    }
+    fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+}
+
+/// Get the progress of a timer, in the `0.0..=1.0` range
+///
+/// Unlike [`Timer::percent`], this also works for zero-duration timers.
+fn timer_progress(timer: &Timer) -> f32 {
+    if timer.finished() {
+        1.0
+    } else {
+        timer.percent()
+    }
+}
+
+/// Make a timer finish immediately
+fn timer_finish(timer: &mut Timer) {
+    let remaining = timer.duration().saturating_sub(timer.elapsed());
+    timer.tick(remaining);
+}
+
+fn splash_clock(
+    config: Res<SplashConfig>,
+    mut clock: ResMut<SplashTime>,
+    paused: Option<Res<SplashPaused>>,
+    awaiting: Option<Res<SplashAwaitingInteraction>>,
+    scale: Option<Res<SplashTimeScale>>,
+    test_clock: Option<Res<SplashTestClock>>,
+    t: Res<Time>,
+) {
+    let delta = match (test_clock, config.clock) {
+        (Some(test_clock), _) => test_clock.0,
+        (None, SplashClock::Virtual) => t.delta(),
+        (None, SplashClock::Real) => {
+            let now = Instant::now();
+            // on the first frame, we have nothing to compare against
+            let delta = clock
+                .last_update
+                .map(|last| now.saturating_duration_since(last))
+                .unwrap_or_else(|| t.delta());
+            clock.last_update = Some(now);
+            delta
+        }
+    };
+    let scale = scale.map(|scale| scale.0).unwrap_or(1.0);
+    let delta = if paused.is_some() || awaiting.is_some() || scale.is_nan() || scale <= 0.0 {
+        Duration::ZERO
+    } else if scale != 1.0 && !delta.is_zero() {
+        // (an unscaled delta is kept exact, `mul_f32` would round it,
+        // and panic if the result does not fit in a `Duration`)
+        Duration::try_from_secs_f32(delta.as_secs_f32() * scale)
+            .map_or(SplashTimeScale::MAX_STEP, |delta| delta.min(SplashTimeScale::MAX_STEP))
+    } else {
+        delta
+    };
+    clock.delta = delta;
+    clock.elapsed = clock.elapsed.saturating_add(delta);
+}
+
+fn splash_anchor(
+    mut reader: Local<ManualEventReader<WindowResized>>,
+    events: Option<Res<Events<WindowResized>>>,
+    windows: Option<Res<Windows>>,
+    mut q: Query<(&SplashAnchor, ChangeTrackers<SplashAnchor>, &mut Transform)>,
+) {
+    // headless apps have no windows
+    let (window, events) = match (windows.as_ref().and_then(|windows| windows.get_primary()), events) {
+        (Some(window), Some(events)) => (window, events),
+        _ => return,
+    };
+    let primary = window.id();
+    // `iter` must run to the end, so we do not see the same events next frame
+    let resized = reader.iter(&events).filter(|ev| ev.id == primary).count() > 0;
+    for (anchor, tracker, mut xf) in q.iter_mut() {
+        // newly spawned entities are positioned for the current window size
+        if resized || tracker.is_changed() {
+            let pos = anchor.position(window.height());
+            xf.translation.x = pos.x;
+            xf.translation.y = pos.y;
+        }
+    }
+}
+
+fn splash_fit(
+    windows: Option<Res<Windows>>,
+    images: Option<Res<Assets<Image>>>,
+    mut q: Query<(&SplashItemFit, &Sprite, &Handle<Image>, &mut Transform)>,
+) {
+    let window = match windows.as_ref().and_then(|windows| windows.get_primary()) {
+        Some(window) => Vec2::new(window.width(), window.height()),
+        None => return,
+    };
+    for (fit, sprite, image, mut xf) in q.iter_mut() {
+        let size = sprite.custom_size.or_else(|| {
+            images.as_ref()
+                .and_then(|images| images.get(image))
+                .map(|image| image.size())
+        });
+        // the image has not loaded yet
+        let size = match size {
+            Some(size) if size.x > 0.0 && size.y > 0.0 => size,
+            _ => continue,
+        };
+        let scale = fit.scale(size, window);
+        // cheap to compute every frame, but avoid triggering change detection
+        if xf.scale.x != scale || xf.scale.y != scale {
+            xf.scale.x = scale;
+            xf.scale.y = scale;
+        }
+    }
+}
+
+fn splash_assets(
+    mut assets: ResMut<SplashAssets>,
+    server: Option<Res<AssetServer>>,
+) {
+    use bevy::asset::LoadState;
+
+    if assets.pending.is_empty() {
+        return;
+    }
+    let server = match server {
+        Some(server) => server,
+        None => {
+            error!("Splash screen is waiting for assets, but there is no AssetServer!");
+            assets.pending.clear();
+            return;
+        }
+    };
+    assets.pending.retain(|handle| match server.get_load_state(handle) {
+        LoadState::Loaded => false,
+        LoadState::Failed => {
+            let path = server.get_handle_path(handle);
+            error!("Splash screen asset {:?} failed to load, not waiting for it.", path);
+            false
+        }
+        _ => true,
+    });
+}
+
+#[allow(clippy::type_complexity)]
+fn splash_timeout(
+    mut q: Query<(
+        &mut SplashItemTimeout,
+        Option<&mut SplashItemRepeat>,
+        Option<&SplashItemOrder>,
+        Option<&SplashItemOptional>,
+    )>,
+    mut items: ResMut<SplashItems>,
+    clock: Res<SplashTime>,
+) {
+    for (mut timeout, repeat, order, optional) in q.iter_mut() {
+        let delta = items.delta(order, &clock);
+        let skipped = items.is_skipped(order);
+        if let Some(mut repeat) = repeat {
+            repeat.tick(&mut timeout.0, delta);
+            if skipped {
+                repeat.finish(&mut timeout.0);
+            }
+            items.track(order, optional, repeat.finished(), repeat.progress(&timeout.0), Some(repeat.remaining(&timeout.0)));
+        } else {
+            timeout.0.tick(delta);
+            if skipped {
+                timer_finish(&mut timeout.0);
+            }
+            items.track_timer(order, optional, &timeout.0);
+        }
+    }
+}
+
+fn splash_scale(
+    mut q: Query<(&mut Transform, &mut SplashItemScale, Option<&SplashItemOrder>, Option<&SplashItemOptional>)>,
+    mut items: ResMut<SplashItems>,
+    clock: Res<SplashTime>,
+) {
+    for (mut xf, mut scale, order, optional) in q.iter_mut() {
+        scale.timer.tick(items.delta(order, &clock));
+        if items.is_skipped(order) {
+            timer_finish(&mut scale.timer);
+        }
+        xf.scale = scale.start_scale.lerp(scale.end_scale, timer_progress(&scale.timer));
+        items.track_timer(order, optional, &scale.timer);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn splash_move(
+    mut q: Query<(
+        &mut Transform,
+        &mut SplashItemMove,
+        Option<&SplashItemOrder>,
+        Option<&SplashItemOptional>,
+        Option<&SplashLayer>,
+    )>,
+    mut items: ResMut<SplashItems>,
+    clock: Res<SplashTime>,
+) {
+    for (mut xf, mut mv, order, optional, layer) in q.iter_mut() {
+        mv.timer.tick(items.delta(order, &clock));
+        if items.is_skipped(order) {
+            timer_finish(&mut mv.timer);
+        }
+        let mut translation = mv.start.lerp(mv.end, timer_progress(&mv.timer));
+        if let Some(layer) = layer {
+            translation.z = layer.0 as f32;
+        }
+        xf.translation = translation;
+        items.track_timer(order, optional, &mv.timer);
+    }
+}
+
+fn splash_layer(
+    mut q: Query<(&SplashLayer, &mut Transform)>,
+) {
+    for (layer, mut xf) in q.iter_mut() {
+        let z = layer.0 as f32;
+        // avoid triggering change detection
+        if xf.translation.z != z {
+            xf.translation.z = z;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn splash_blink(
+    mut q: Query<(
+        &mut SplashItemBlink,
+        Option<&SplashItemOrder>,
+        Option<&SplashItemOptional>,
+        Option<&mut Sprite>,
+        Option<&mut UiColor>,
+    )>,
+    mut items: ResMut<SplashItems>,
+    clock: Res<SplashTime>,
+) {
+    for (mut blink, order, optional, sprite, ui_color) in q.iter_mut() {
+        blink.elapsed += items.delta(order, &clock);
+        if items.is_skipped(order) {
+            if let Some(duration) = blink.duration() {
+                blink.elapsed = blink.elapsed.max(duration);
+            }
+        }
+        let alpha = if items.is_active(order) {
+            blink.alpha()
+        } else {
+            // stay hidden until our group gets to play
+            0.0
+        };
+        if let Some(mut sprite) = sprite {
+            sprite.set_alpha(alpha);
+        }
+        if let Some(mut ui_color) = ui_color {
+            ui_color.set_alpha(alpha);
+        }
+        items.mark_spawned();
+        items.track_shown(alpha > 0.0);
+        // blinking forever must not hold up the splash screen
+        if let Some(duration) = blink.duration() {
+            let progress = if duration.is_zero() {
+                1.0
+            } else {
+                blink.elapsed.as_secs_f32() / duration.as_secs_f32()
+            };
+            let remaining = duration.saturating_sub(blink.elapsed);
+            items.track(order, optional, blink.finished(), progress, Some(remaining));
+        }
+    }
+}
+
+#[cfg(feature = "audio")]
+fn splash_audio(
+    mut q: Query<(&mut SplashItemAudio, Option<&SplashItemOrder>, Option<&SplashItemOptional>)>,
+    mut items: ResMut<SplashItems>,
+    ended: Res<SplashEnded>,
+    audio: Res<Audio>,
+    sinks: Res<Assets<AudioSink>>,
+    clock: Res<SplashTime>,
+    awaiting: Option<Res<SplashAwaitingInteraction>>,
+) {
+    for (mut item, order, optional) in q.iter_mut() {
+        if item.sink.is_none() && items.is_active(order) && awaiting.is_none() {
+            let sink = audio.play(item.source.clone());
+            item.sink = Some(sinks.get_handle(sink));
+        }
+        if ended.0 == Some(SplashEndReason::Skipped) || items.is_skipped(order) {
+            item.stop(&sinks);
+        }
+        item.timer.tick(items.delta(order, &clock));
+        if items.is_skipped(order) {
+            timer_finish(&mut item.timer);
+        }
+        items.track_timer(order, optional, &item.timer);
+    }
+}
+
+/// Make sure skipped sounds do not keep playing after the splash screen
+///
+/// (they might not have started playing yet, at the time of the skip)
+#[cfg(feature = "audio")]
+fn splash_audio_stop(
+    q: Query<&SplashItemAudio>,
+    ended: Option<Res<SplashEnded>>,
+    sinks: Res<Assets<AudioSink>>,
+) {
+    if ended.and_then(|ended| ended.0) == Some(SplashEndReason::Skipped) {
+        for item in q.iter() {
+            item.stop(&sinks);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn splash_fade(
+    mut commands: Commands,
+    mut q: Query<(
+        Entity,
+        &mut SplashItemFade,
+        Option<&SplashItemOrder>,
+        Option<&SplashItemOptional>,
+        Option<&mut SplashItemProgress>,
+        Option<&Handle<Image>>,
+        Option<&UiImage>,
+        Option<&SplashItemFollow>,
+    )>,
+    mut items: ResMut<SplashItems>,
+    clock: Res<SplashTime>,
+    paused: Option<Res<SplashPaused>>,
+    awaiting: Option<Res<SplashAwaitingInteraction>>,
+    images: Option<Res<Assets<Image>>>,
+    server: Option<Res<AssetServer>>,
+    #[cfg(feature = "audio")]
+    sinks: Option<Res<Assets<AudioSink>>>,
+    config: Res<SplashConfig>,
+    mut warned: Local<HashSet<Entity>>,
+) {
+    let _span = config.span.enter();
+    // for items counting frames
+    let frame = if paused.is_some() || awaiting.is_some() { Duration::ZERO } else { FADE_FRAME };
+    // items must be advanced after the items they follow, to start on the
+    // same frame as those finish their intro; for each advanced item, keep
+    // how far into this frame its intro finished, if it did on this frame
+    let mut pending: Vec<Entity> = q.iter().map(|(e, ..)| e).collect();
+    let mut advanced: HashMap<Entity, Option<Duration>> = HashMap::new();
+    while !pending.is_empty() {
+        let (mut ready, rest): (Vec<Entity>, Vec<Entity>) = pending.iter().partition(|&&e| {
+            match q.get(e) {
+                Ok((.., Some(follow))) => !q.contains(follow.0) || advanced.contains_key(&follow.0),
+                _ => true,
+            }
+        });
+        let mut ignore_follow = false;
+        if ready.is_empty() {
+            // everything left follows each other in a cycle
+            for &e in &rest {
+                if warned.insert(e) {
+                    warn!("Splash item {:?} is part of a SplashItemFollow cycle, ignoring it.", e);
+                }
+            }
+            ready = rest;
+            pending = Vec::new();
+            ignore_follow = true;
+        } else {
+            pending = rest;
+        }
+        for e in ready {
+            let followed = q.get(e).ok()
+                .and_then(|(.., follow)| follow.copied())
+                .filter(|_| !ignore_follow)
+                .and_then(|follow| q.get(follow.0).ok());
+            let follow = match followed {
+                Some((_, fade, ..)) if !fade.intro_finished() => {
+                    SplashFollowState::Waiting(fade.to_time(fade.intro_remaining(), clock.delta))
+                }
+                Some((followed, ..)) => {
+                    let delay = advanced.get(&followed).copied().flatten();
+                    SplashFollowState::Started(delay.unwrap_or_default())
+                }
+                None => SplashFollowState::Started(Duration::ZERO),
+            };
+            let (_, mut fade, order, optional, progress, image, ui_image, _) = match q.get_mut(e) {
+                Ok(item) => item,
+                Err(_) => continue,
+            };
+            let image = image.or(ui_image.map(|ui_image| &ui_image.0));
+            let loading = splash_image_loading(images.as_deref(), server.as_deref(), image);
+            #[cfg(feature = "audio")]
+            fade.update_audio_sync(sinks.as_deref(), items.delta(order, &clock));
+            let intro = splash_fade_item(&mut commands, e, &mut fade, order, optional, progress, follow, loading, frame, &mut items, &clock);
+            advanced.insert(e, intro);
+        }
+    }
+}
+
+/// Where a [`SplashItemFade`] is, relative to the item it follows
+#[derive(Debug, Clone, Copy)]
+enum SplashFollowState {
+    /// Free to play, starting this far into the current frame
+    Started(Duration),
+    /// Has to wait, for this long (at least)
+    Waiting(Duration),
+}
+
+/// Is the image of a splash item still loading?
+///
+/// Images that failed to load will never show up, so they do not count.
+fn splash_image_loading(
+    images: Option<&Assets<Image>>,
+    server: Option<&AssetServer>,
+    image: Option<&Handle<Image>>,
+) -> bool {
+    use bevy::asset::LoadState;
+
+    // without a renderer (such as in headless apps), there are no images
+    let (images, image) = match (images, image) {
+        (Some(images), Some(image)) => (images, image),
+        _ => return false,
+    };
+    !images.contains(image)
+        && server.is_none_or(|server| server.get_load_state(image) != LoadState::Failed)
+}
+
+/// Advance a single [`SplashItemFade`]
+///
+/// Items counting frames advance by `frame`, instead of time. Items whose
+/// image is `loading` do not advance past their wait phase. Returns how
+/// far into this frame the intro phase finished, if it did on this frame.
+#[allow(clippy::too_many_arguments)]
+fn splash_fade_item(
+    commands: &mut Commands,
+    e: Entity,
+    fade: &mut SplashItemFade,
+    order: Option<&SplashItemOrder>,
+    optional: Option<&SplashItemOptional>,
+    progress: Option<Mut<SplashItemProgress>>,
+    follow: SplashFollowState,
+    loading: bool,
+    frame: Duration,
+    items: &mut SplashItems,
+    clock: &SplashTime,
+) -> Option<Duration> {
+    let intro_finished = fade.intro_finished();
+    let intro_remaining = fade.intro_remaining();
+    let phase = fade.phase_name();
+    let (alpha, finished) = match follow {
+        _ if items.is_skipped(order) && !fade.timer_fade.finished() => {
+            fade.finish();
+            // jump to the end, as there will be no more updates to its alpha
+            (Some(fade.min_alpha), true)
+        }
+        // stay hidden until our group (or the item we follow) gets to play
+        _ if !items.is_active(order) => (Some(0.0), false),
+        SplashFollowState::Waiting(_) => (Some(0.0), false),
+        // (a whole frame is a whole frame, no matter when it started)
+        SplashFollowState::Started(_) if fade.frames => {
+            fade.advance(fade.hold_while_loading(loading, frame))
+        }
+        SplashFollowState::Started(delay) => {
+            let delta = fade.synced_delta(items.delta(order, clock)).saturating_sub(delay);
+            fade.advance(fade.hold_while_loading(loading, delta))
+        }
+    };
+    fade.alpha = alpha;
+    if let Some(alpha) = alpha {
+        fade.phase_alpha = alpha;
+    }
+    // (the alpha is no indication, with `min_alpha` above zero)
+    items.track_shown(fade.intro_started());
+    if fade.phase_name() != phase {
+        trace!(item = ?e, from = phase, to = fade.phase_name(), cycle = fade.cycles_done, "Splash item changed phase");
+    }
+    let fraction = fade.progress();
+    match progress {
+        Some(mut progress) => {
+            if progress.0 != fraction {
+                progress.0 = fraction;
+            }
+        }
+        None => {
+            commands.entity(e).insert(SplashItemProgress(fraction));
+        }
+    }
+    let remaining = fade.remaining().map(|remaining| {
+        let remaining = fade.to_time(remaining, clock.delta);
+        match follow {
+            SplashFollowState::Waiting(waiting) => remaining + waiting,
+            SplashFollowState::Started(_) => remaining,
+        }
+    });
+    items.track(order, optional, finished, fraction, remaining);
+    match follow {
+        SplashFollowState::Started(delay) if !intro_finished && fade.intro_finished() => {
+            let intro_remaining = fade.to_time(intro_remaining, clock.delta);
+            Some((delay + intro_remaining).min(clock.delta))
+        }
+        _ => None,
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn splash_hover(
+    windows: Option<Res<Windows>>,
+    images: Option<Res<Assets<Image>>>,
+    mut q: Query<(
+        &mut SplashItemHover,
+        Option<&mut SplashItemFade>,
+        &mut Transform,
+        &GlobalTransform,
+        Option<&Sprite>,
+        Option<&Handle<Image>>,
+    )>,
+    mut items: ResMut<SplashItems>,
+) {
+    // headless apps have no windows, and no cursor
+    let cursor = windows.as_ref()
+        .and_then(|windows| windows.get_primary())
+        .and_then(|window| {
+            let size = Vec2::new(window.width(), window.height());
+            window.cursor_position().map(|pos| pos - size / 2.0)
+        });
+    for (mut hover, fade, mut xf, global, sprite, image) in q.iter_mut() {
+        items.mark_spawned();
+        let amount = match cursor {
+            Some(cursor) => {
+                let (scale, _, center) = global.to_scale_rotation_translation();
+                let size = sprite.and_then(|sprite| {
+                    sprite.custom_size.or_else(|| {
+                        images.as_ref()
+                            .zip(image)
+                            .and_then(|(images, image)| images.get(image))
+                            .map(|image| image.size())
+                    })
+                });
+                let half = size.unwrap_or(Vec2::ZERO) * scale.truncate().abs() / 2.0;
+                // distance to the nearest edge, or zero if inside
+                let outside = ((cursor - center.truncate()).abs() - half).max(Vec2::ZERO);
+                if hover.radius > 0.0 {
+                    (1.0 - outside.length() / hover.radius).clamp(0.0, 1.0)
+                } else if outside == Vec2::ZERO {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            None => 0.0,
+        };
+        let changed = hover.amount != amount;
+        if changed {
+            hover.amount = amount;
+        }
+        if let Some(mut fade) = fade {
+            if changed || fade.alpha.is_some() {
+                fade.alpha = Some(fade.phase_alpha * hover.alpha_factor());
+            }
+        }
+        if let Some(scale) = hover.rescale(xf.scale) {
+            xf.scale = scale;
+        }
+    }
+}
+
+fn splash_fade_target<T: SplashColorTarget + Component>(
+    mut q: Query<(&mut SplashItemFade, &mut T)>,
+) {
+    for (mut fade, mut target) in q.iter_mut() {
+        if let Some(alpha) = fade.alpha {
+            let premultiply = fade.premultiply_base(target.get_color());
+            target.apply_fade(alpha, fade.tint(), premultiply);
+        }
+    }
+}
+
+/// Text has a color per section, so their relative alpha must be kept
+fn splash_fade_text(
+    mut q: Query<(&mut SplashItemFade, &mut Text)>,
+) {
+    for (mut fade, mut text) in q.iter_mut() {
+        if let Some(alpha) = fade.alpha {
+            let tint = fade.tint();
+            let premultiply = fade.alpha_mode == SplashAlphaMode::Premultiplied;
+            let base = fade.text_colors.get_or_insert_with(|| {
+                text.sections.iter().map(|section| section.get_color()).collect()
+            });
+            for (section, base) in text.sections.iter_mut().zip(base.iter()) {
+                section.apply_fade(alpha * base.a(), tint, premultiply.then_some(*base));
+            }
+        }
+    }
+}
+
+/// Materials are shared, so each item gets its own copy to fade
+fn splash_fade_material(
+    mut q: Query<(&mut SplashItemFade, &mut Handle<ColorMaterial>)>,
+    materials: Option<ResMut<Assets<ColorMaterial>>>,
+) {
+    let mut materials = match materials {
+        Some(materials) => materials,
+        None => return,
+    };
+    for (mut fade, mut handle) in q.iter_mut() {
+        let alpha = match fade.alpha {
+            Some(alpha) => alpha,
+            None => continue,
+        };
+        if fade.material.as_ref() != Some(&*handle) {
+            let copy = match materials.get(&*handle).cloned() {
+                Some(material) => materials.add(material),
+                None => continue,
+            };
+            *handle = copy.clone();
+            fade.material = Some(copy.clone_weak());
+        }
+        if let Some(material) = materials.get_mut(&*handle) {
+            let premultiply = fade.premultiply_base(material.color);
+            material.apply_fade(alpha, fade.tint(), premultiply);
+        }
+    }
+}
+
+/// Check if the splash screen ends on this frame, and reset the item tally
+///
+/// Returns the reason only once: on the frame when the splash screen ends.
+#[allow(clippy::too_many_arguments)]
+fn splash_check_end(
+    items: &mut SplashItems,
+    skip: &SplashSkipRequested,
+    ended: &mut SplashEnded,
+    config: &SplashConfig,
+    clock: &SplashTime,
+    assets: Option<&SplashAssets>,
+    waiting: Option<&SplashWaiting>,
+    load: Option<&SplashLoadProgress>,
+    status: &mut SplashState,
+) -> Option<SplashEndReason> {
+    let waiting = waiting.is_some_and(|waiting| waiting.0);
+    let loading = assets.is_some_and(|assets| !assets.pending.is_empty());
+    let unready = load.is_some_and(|load| load.fraction < load.min);
+    let skip_blocked = loading || unready || (waiting && config.skip_waits_for_condition);
+    *status = SplashState {
+        elapsed: clock.elapsed,
+        remaining: items.remaining(),
+        skip_requested: skip.0,
+    };
+    let reason = if ended.0.is_some() {
+        None
+    } else if config.skip_all || (skip.0 && !skip_blocked && clock.elapsed >= config.min_duration) {
+        Some(SplashEndReason::Skipped)
+    } else if items.skipped_last() && !skip_blocked {
+        (clock.elapsed >= config.min_duration).then_some(SplashEndReason::Skipped)
+    } else if items.all_settled() && !loading && !waiting {
+        Some(SplashEndReason::Completed)
+    } else if let Some(max) = config.max_duration.filter(|max| clock.elapsed >= *max) {
+        warn!(
+            "Splash screen did not end within its max_duration ({:?}), forcing it to end.",
+            max
+        );
+        Some(SplashEndReason::TimedOut)
+    } else {
+        None
+    };
+    if let Some(reason) = reason {
+        let _span = config.span.enter();
+        info!(reason = ?reason, elapsed = ?clock.elapsed, "Splash screen ended");
+        ended.0 = Some(reason);
+        if let Some(signal) = &config.signal {
+            signal.fire(reason);
+        }
+    }
+    items.reset();
+    reason
+}
+
+#[allow(clippy::too_many_arguments)]
+fn splash_complete<S: StateData>(
+    mut commands: Commands,
+    mut items: ResMut<SplashItems>,
+    mut ended: ResMut<SplashEnded>,
+    mut status: ResMut<SplashState>,
+    mut evw: EventWriter<SplashCompleted<S>>,
+    skip: Res<SplashSkipRequested>,
+    config: Res<SplashConfig>,
+    clock: Res<SplashTime>,
+    assets: Option<Res<SplashAssets>>,
+    waiting: Option<Res<SplashWaiting>>,
+    exiting: Option<Res<SplashExitFading>>,
+    next: Res<SplashNext<S>>,
+    ret: Option<Res<SplashReturn<S>>>,
+    state: Res<SplashCurrent<S>>,
+    q_next: Query<(Entity, &SplashItemNext<S>)>,
+) {
+    let transition = if let Some(reason) = splash_check_end(&mut items, &skip, &mut ended, &config, &clock, assets.as_deref(), waiting.as_deref(), None, &mut status) {
+        evw.send(SplashCompleted {
+            state: state.0.clone(),
+            reason,
+        });
+        match &config.exit_fade {
+            // skipping all splash screens should be instant
+            Some(fade) if !config.skip_all => {
+                splash_exit_fade_start(&mut commands, fade);
+                false
+            }
+            _ => true,
+        }
+    } else {
+        exiting.is_some_and(|exiting| exiting.done())
+    };
+    if transition {
+        let next = q_next.iter()
+            .min_by_key(|(e, _)| e.id())
+            .map(|(_, next)| &next.0)
+            .or_else(|| {
+                ret.as_ref()
+                    .filter(|ret| ret.splash == state.0)
+                    .and_then(|ret| ret.previous.as_ref())
+            })
+            .or_else(|| next.0.get(&state.0));
+        let _span = config.span.enter();
+        if let Some(next) = next {
+            info!(state = ?state.0, next = ?next, "Leaving the splash screen");
+            commands.add(SplashTransition(next.clone()));
+        } else {
+            debug!(state = ?state.0, "Splash screen has no next state, staying in it");
+        }
+    }
+}
+
+#[cfg(feature = "iyes_progress")]
+#[allow(clippy::too_many_arguments)]
+fn splash_progress<S: StateData>(
+    mut commands: Commands,
+    mut items: ResMut<SplashItems>,
+    mut ended: ResMut<SplashEnded>,
+    mut status: ResMut<SplashState>,
+    mut evw: EventWriter<SplashCompleted<S>>,
+    skip: Res<SplashSkipRequested>,
+    config: Res<SplashConfig>,
+    clock: Res<SplashTime>,
+    assets: Option<Res<SplashAssets>>,
+    waiting: Option<Res<SplashWaiting>>,
+    exiting: Option<Res<SplashExitFading>>,
+    state: Res<SplashCurrent<S>>,
+    progress: Res<SplashProgressConfig>,
+    mut load: Option<ResMut<SplashLoadProgress>>,
+) -> iyes_progress::Progress {
+    let fraction = items.progress();
+    if let Some(reason) = splash_check_end(&mut items, &skip, &mut ended, &config, &clock, assets.as_deref(), waiting.as_deref(), load.as_deref(), &mut status) {
+        evw.send(SplashCompleted {
+            state: state.0.clone(),
+            reason,
+        });
+        if let Some(fade) = config.exit_fade.as_ref().filter(|_| !config.skip_all) {
+            splash_exit_fade_start(&mut commands, fade);
+        }
+    }
+    let total = match progress.visibility {
+        ProgressVisibility::Visible => progress.weight,
+        ProgressVisibility::Hidden => 1,
+    };
+    let exited = config.exit_fade.is_none()
+        || config.skip_all
+        || exiting.is_some_and(|exiting| exiting.done());
+    let done = if ended.0.is_some() && exited {
+        total
+    } else {
+        // never report full progress before the splash screen has ended
+        ((fraction * total as f32) as u32).min(total - 1)
+    };
+    if let Some(load) = &mut load {
+        load.own = (done, total);
+    }
+    iyes_progress::Progress {
+        done,
+        total,
+    }
+}
+
+#[cfg(feature = "iyes_progress")]
+fn splash_progress_text(
+    mut q: Query<(&mut Text, &SplashProgressText)>,
+    progress: Option<Res<iyes_progress::ProgressCounter>>,
+) {
+    let progress: f32 = match progress {
+        Some(progress) => progress.progress().into(),
+        None => return,
+    };
+    // nothing has reported any progress yet
+    let progress = if progress.is_nan() { 0.0 } else { progress };
+    let pct = format!("{:.0}", progress * 100.0);
+    for (mut text, fmt) in q.iter_mut() {
+        if let Some(section) = text.sections.first_mut() {
+            section.value = fmt.format.replace("{}", &pct);
+        }
+    }
+}
+
+/// Everything needed to skip the splash screen
+#[derive(SystemParam)]
+struct SplashSkipper<'w, 's, S: StateData> {
+    skip: ResMut<'w, SplashSkipRequested>,
+    evw: EventWriter<'w, 's, SplashSkipped<S>>,
+    ended: Res<'w, SplashEnded>,
+    config: Res<'w, SplashConfig>,
+    clock: Res<'w, SplashTime>,
+    state: Res<'w, SplashCurrent<S>>,
+    paused: Option<Res<'w, SplashPaused>>,
+    awaiting: Option<Res<'w, SplashAwaitingInteraction>>,
+    items: ResMut<'w, SplashItems>,
+}
+
+impl<'w, 's, S: StateData> SplashSkipper<'w, 's, S> {
+    /// Is the splash screen accepting skip input right now?
+    fn can_skip(&self) -> bool {
+        // once the splash screen has ended, it can no longer be skipped
+        !self.skip.0 && self.ended.0.is_none()
+            && self.paused.is_none()
+            && self.awaiting.is_none()
+            && self.clock.elapsed >= self.config.skip_after
+            // there is nothing to skip, until the first items have been spawned
+            && self.items.spawned
+            && (!self.config.skip_once_visible || self.items.any_shown())
+    }
+
+    /// Skip the splash screen (if it is accepting skip input)
+    fn skip(&mut self) {
+        if self.can_skip() {
+            let _span = self.config.span.enter();
+            match self.config.skip_mode {
+                SkipMode::Whole => info!("Splash screen skipped"),
+                SkipMode::Item => info!(group = self.items.active, "Splash screen group skipped"),
+            }
+            self.evw.send(SplashSkipped {
+                state: self.state.0.clone(),
+            });
+            match self.config.skip_mode {
+                SkipMode::Whole => self.skip.0 = true,
+                SkipMode::Item => self.items.skipped = Some(self.items.active),
+            }
+        }
+    }
+}
+
+/// Stop the input that skipped the splash screen from leaking into the next state
+///
+/// The "just pressed" state of all buttons is cleared, and all pending
+/// input events are dropped, so that the next state does not see the skip
+/// as a fresh press (such as activating a menu button). Buttons that are
+/// still held down remain pressed, as they really are.
+#[allow(clippy::too_many_arguments)]
+fn splash_consume_input(
+    ended: Option<Res<SplashEnded>>,
+    keys: Option<ResMut<Input<KeyCode>>>,
+    mouse: Option<ResMut<Input<MouseButton>>>,
+    gamepad: Option<ResMut<Input<GamepadButton>>>,
+    kbd_events: Option<ResMut<Events<KeyboardInput>>>,
+    mouse_events: Option<ResMut<Events<MouseButtonInput>>>,
+    gamepad_events: Option<ResMut<Events<GamepadEvent>>>,
+    touch_events: Option<ResMut<Events<TouchInput>>>,
+) {
+    if ended.and_then(|ended| ended.0) != Some(SplashEndReason::Skipped) {
+        return;
+    }
+    if let Some(mut keys) = keys {
+        keys.clear();
+    }
+    if let Some(mut mouse) = mouse {
+        mouse.clear();
+    }
+    if let Some(mut gamepad) = gamepad {
+        gamepad.clear();
+    }
+    if let Some(mut events) = kbd_events {
+        events.clear();
+    }
+    if let Some(mut events) = mouse_events {
+        events.clear();
+    }
+    if let Some(mut events) = gamepad_events {
+        events.clear();
+    }
+    if let Some(mut events) = touch_events {
+        events.clear();
+    }
+}
+
+fn splash_skip<S: StateData>(
+    mut skipper: SplashSkipper<S>,
+    mut kbd: EventReader<KeyboardInput>,
+    mut mouse: EventReader<MouseButtonInput>,
+    mut gamepad: EventReader<GamepadEvent>,
+    mut touch: EventReader<TouchInput>,
+    mut gamepad_held: Local<HashSet<GamepadButton>>,
+    mut confirm: Option<ResMut<SplashSkipConfirm>>,
+) {
+    use bevy::input::ButtonState;
+    use bevy::input::touch::TouchPhase;
+
+    // On the first frame of the splash screen, our event readers would
+    // still see input from before it started, such as the keypress that
+    // skipped a previous splash screen. Discard all of it.
+    if skipper.skip.is_added() {
+        kbd.clear();
+        mouse.clear();
+        gamepad.clear();
+        touch.clear();
+        gamepad_held.clear();
+        return;
+    }
+
+    let config = &skipper.config;
+    let mut done = false;
+
+    // always read all the events, even from disabled sources,
+    // so that they do not pile up for later
+
+    for ev in kbd.iter() {
+        if config.skip_keyboard && ev.state == ButtonState::Pressed {
+            let allowed = match (&config.skip_keys, ev.key_code) {
+                (None, _) => true,
+                (Some(keys), Some(key)) => keys.contains(&key),
+                // cannot tell if unknown keys are allowed
+                (Some(_), None) => false,
+            };
+            if allowed {
+                done = true;
+            }
+        }
+    }
+
+    for ev in mouse.iter() {
+        if config.skip_mouse && ev.state == ButtonState::Pressed {
+            done = true;
+        }
+    }
+
+    for ev in gamepad.iter() {
+        match ev.event_type {
+            GamepadEventType::ButtonChanged(button_type, value) => {
+                // only count the moment the button goes past the threshold
+                let button = GamepadButton::new(ev.gamepad, button_type);
+                if value > config.gamepad_threshold {
+                    if gamepad_held.insert(button) && config.skip_gamepad {
+                        done = true;
+                    }
+                } else {
+                    gamepad_held.remove(&button);
+                }
+            }
+            // plugging in a controller is not a request to skip
+            GamepadEventType::Connected => {}
+            GamepadEventType::Disconnected => {
+                gamepad_held.retain(|button| button.gamepad != ev.gamepad);
+            }
+            // sticks and triggers are noisy, only buttons count
+            GamepadEventType::AxisChanged(..) => {}
+        }
+    }
+
+    for ev in touch.iter() {
+        if config.skip_touch && ev.phase == TouchPhase::Started {
+            done = true;
+        }
+    }
+
+    if let Some(confirm) = &mut confirm {
+        let now = skipper.clock.elapsed;
+        if confirm.first.is_some_and(|first| now.saturating_sub(first) > confirm.window) {
+            confirm.first = None;
+        }
+        if done && skipper.can_skip() {
+            // the first press only asks for confirmation
+            done = confirm.first.take().is_some();
+            if !done {
+                confirm.first = Some(now);
+            }
+        }
+    }
+
+    if done {
+        skipper.skip();
+    }
+}
+
+/// Skip when the `.skip_when(...)` closure says so, instead of [`splash_skip`]
+fn splash_skip_when<S: StateData>(
+    mut skipper: SplashSkipper<S>,
+    triggered: Option<Res<SplashSkipTriggered>>,
+) {
+    if triggered.is_some_and(|triggered| triggered.0) {
+        skipper.skip();
+    }
+}
+
+fn splash_hold_skip<S: StateData>(
+    mut skipper: SplashSkipper<S>,
+    mut hold: ResMut<SplashSkipHold>,
+    mut holding: Local<bool>,
+    keys: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    gamepad: Res<Input<GamepadButton>>,
+    touches: Res<Touches>,
+) {
+    let config = &skipper.config;
+
+    let keys_pressed = match &config.skip_keys {
+        None => keys.get_pressed().len() > 0,
+        Some(list) => keys.any_pressed(list.iter().copied()),
+    };
+    let keys_just_pressed = match &config.skip_keys {
+        None => keys.get_just_pressed().len() > 0,
+        Some(list) => keys.any_just_pressed(list.iter().copied()),
+    };
+
+    let pressed = (config.skip_keyboard && keys_pressed)
+        || (config.skip_mouse && mouse.get_pressed().len() > 0)
+        || (config.skip_gamepad && gamepad.get_pressed().len() > 0)
+        || (config.skip_touch && touches.iter().next().is_some());
+    let just_pressed = (config.skip_keyboard && keys_just_pressed)
+        || (config.skip_mouse && mouse.get_just_pressed().len() > 0)
+        || (config.skip_gamepad && gamepad.get_just_pressed().len() > 0)
+        || (config.skip_touch && touches.any_just_pressed());
+
+    // Only count holds that started during the splash screen,
+    // not input that was already held down before it.
+    if hold.is_added() || !pressed || skipper.awaiting.is_some() {
+        *holding = false;
+    } else if just_pressed {
+        *holding = true;
+    }
+
+    // (with a zero duration, a press must still skip, not the lack of one)
+    let counting = *holding && skipper.can_skip();
+    if counting {
+        hold.held += skipper.clock.delta;
+    } else {
+        hold.held = Duration::ZERO;
+    }
+
+    if counting && hold.held >= hold.required {
+        skipper.skip();
+        // with `SkipMode::Item`, every skip needs a new hold
+        *holding = false;
+        hold.held = Duration::ZERO;
+    }
+}
+
+#[cfg(all(test, feature = "iyes_loopless"))]
+mod tests;
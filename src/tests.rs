@@ -0,0 +1,1319 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use bevy::input::ButtonState;
+use bevy::utils::tracing::{self, span};
+use iyes_loopless::prelude::*;
+
+use super::*;
+
+/// Every update advances the splash screen by exactly this much
+const FRAME: Duration = Duration::from_millis(100);
+
+/// The systems that drive splash items, without the rest of the plugin
+fn item_stage() -> SystemStage {
+    SystemStage::single_threaded()
+        .with_system(splash_clock.label(SplashLabel::Clock))
+        .with_system_set(
+            SystemSet::new()
+                .label(SplashLabel::Items)
+                .after(SplashLabel::Clock)
+                .with_system(splash_fade)
+                .with_system(splash_timeout)
+                .with_system(splash_scale)
+                .with_system(splash_blink)
+        )
+        .with_system_set(
+            SystemSet::new()
+                .after(SplashLabel::Items)
+                .with_system(splash_fade_target::<Sprite>)
+                .with_system(splash_fade_target::<UiColor>)
+                .with_system(splash_fade_text)
+        )
+}
+
+/// Insert a `Time` that only advances when told to, and a fresh splash clock
+fn insert_manual_time(world: &mut World) {
+    let mut time = Time::default();
+    time.update_with_instant(time.startup());
+    world.insert_resource(time);
+    world.insert_resource(splash(TestState::Splash, TestState::Menu).config());
+    world.insert_resource(SplashTime::default());
+}
+
+/// Run one frame of `stage`, `FRAME` after the previous one
+fn step(world: &mut World, stage: &mut SystemStage) {
+    step_by(world, stage, FRAME);
+}
+
+/// Run one frame of `stage`, `delta` after the previous one
+fn step_by(world: &mut World, stage: &mut SystemStage, delta: Duration) {
+    match world.get_resource_mut::<SplashItems>() {
+        Some(mut items) => items.reset(),
+        None => world.insert_resource(SplashItems::default()),
+    }
+    let mut time = world.resource_mut::<Time>();
+    let last = time.last_update().unwrap();
+    time.update_with_instant(last + delta);
+    stage.run(world);
+}
+
+/// Run the item systems for `frames` frames
+fn step_items(world: &mut World, frames: u32) {
+    let mut stage = item_stage();
+    insert_manual_time(world);
+    for _ in 0..frames {
+        step(world, &mut stage);
+    }
+}
+
+/// Run the item systems, one frame at a time, until all items have finished
+///
+/// Returns the number of frames that took.
+fn frames_until_finished(world: &mut World) -> u32 {
+    let mut stage = item_stage();
+    insert_manual_time(world);
+    for frame in 1..=1000 {
+        step(world, &mut stage);
+        if world.resource::<SplashItems>().all_finished() {
+            return frame;
+        }
+    }
+    panic!("the items never finished");
+}
+
+fn alpha(world: &World, entity: Entity) -> f32 {
+    world.get::<Sprite>(entity).unwrap().color.a()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TestState {
+    Splash,
+    Second,
+    Menu,
+}
+
+/// A headless app, starting in `TestState::Splash`
+fn app() -> App {
+    let mut app = app_without_state();
+    app.add_loopless_state(TestState::Splash);
+    app
+}
+
+/// A splash screen that follows the manually updated `Time`
+fn splash(state: TestState, next: TestState) -> SplashPlugin<TestState> {
+    SplashPlugin::new(state, next).clock(SplashClock::Virtual)
+}
+
+/// Everything needed to run splash screens, except for the state
+fn app_without_state() -> App {
+    let mut time = Time::default();
+    time.update_with_instant(time.startup());
+    let mut app = App::new();
+    app.add_plugin(bevy::core::CorePlugin)
+        .add_plugin(bevy::input::InputPlugin)
+        .insert_resource(time);
+    app
+}
+
+/// Run `frames` updates, each `FRAME` after the previous one
+fn update(app: &mut App, frames: u32) {
+    for _ in 0..frames {
+        let mut time = app.world.resource_mut::<Time>();
+        let last = time.last_update().unwrap();
+        time.update_with_instant(last + FRAME);
+        app.update();
+    }
+}
+
+/// Run `frames` updates, without advancing `Time`
+fn update_paused(app: &mut App, frames: u32) {
+    for _ in 0..frames {
+        let mut time = app.world.resource_mut::<Time>();
+        let last = time.last_update().unwrap();
+        time.update_with_instant(last);
+        app.update();
+    }
+}
+
+fn state(app: &App) -> TestState {
+    app.world.resource::<CurrentState<TestState>>().0
+}
+
+/// Reasons of the [`SplashCompleted`] events sent on the last two updates
+fn completed(app: &App) -> Vec<SplashEndReason> {
+    let events = app.world.resource::<Events<SplashCompleted<TestState>>>();
+    events.get_reader().iter(events).map(|ev| ev.reason).collect()
+}
+
+/// Spawn a splash item every time `state` is entered
+fn spawn_on_enter<B: Bundle>(
+    app: &mut App,
+    state: TestState,
+    bundle: impl Fn() -> B + Send + Sync + 'static,
+) {
+    app.add_enter_system(state, move |mut commands: Commands| {
+        commands.spawn_bundle(bundle()).insert(SplashCleanup);
+    });
+}
+
+/// Press a key for one update, and release it on the next
+fn tap_key(app: &mut App, key: KeyCode) {
+    for state in [ButtonState::Pressed, ButtonState::Released] {
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key),
+            state,
+        });
+        update(app, 1);
+    }
+}
+
+/// Press or release a key, taking effect on the next update
+fn hold_key(app: &mut App, key: KeyCode, state: ButtonState) {
+    app.world.send_event(KeyboardInput {
+        scan_code: 0,
+        key_code: Some(key),
+        state,
+    });
+}
+
+/// Move a gamepad trigger to `value`, for one update
+fn pull_trigger(app: &mut App, value: f32) {
+    app.world.send_event(GamepadEvent::new(
+        Gamepad::new(0),
+        GamepadEventType::ButtonChanged(GamepadButtonType::RightTrigger2, value),
+    ));
+    update(app, 1);
+}
+
+/// Click the left mouse button
+fn click(app: &mut App) {
+    for state in [ButtonState::Pressed, ButtonState::Released] {
+        app.world.send_event(MouseButtonInput {
+            button: MouseButton::Left,
+            state,
+        });
+        update(app, 1);
+    }
+}
+
+#[test]
+fn waits_for_the_longest_item() {
+    let mut world = World::new();
+    world.spawn().insert(SplashItemTimeout::new(1.0));
+    world.spawn().insert_bundle((Sprite::default(), SplashItemFade::new(0.0, 0.25, 0.0, 0.25)));
+    // the fade is done long before the timeout
+    assert_eq!(frames_until_finished(&mut world), 10);
+}
+
+#[test]
+fn skipping_goes_to_the_next_splash_screen() {
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Second));
+    app.add_plugin(splash(TestState::Second, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+    spawn_on_enter(&mut app, TestState::Second, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 2);
+    tap_key(&mut app, KeyCode::Space);
+    assert_eq!(state(&app), TestState::Second);
+    // the same press does not also skip the second one
+    update(&mut app, 5);
+    assert_eq!(state(&app), TestState::Second);
+}
+
+#[test]
+fn skip_after_ignores_early_skips() {
+    let mut app = app();
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .skip_after(Duration::from_secs(1))
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    // presses at 0.1s, 0.3s, ..., 0.9s
+    for _ in 0..5 {
+        tap_key(&mut app, KeyCode::Space);
+    }
+    assert_eq!(state(&app), TestState::Splash);
+    tap_key(&mut app, KeyCode::Space);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn easing_shapes_the_intro() {
+    let mut world = World::new();
+    let linear = world.spawn()
+        .insert_bundle((Sprite::default(), SplashItemFade::new(0.0, 0.4, 1.0, 0.4)))
+        .id();
+    let eased = world.spawn()
+        .insert_bundle((
+            Sprite::default(),
+            SplashItemFade::new(0.0, 0.4, 1.0, 0.4).with_easing(EaseFunction::CubicInOut),
+        ))
+        .id();
+
+    // a quarter of the way in; at the midpoint in-out curves meet the linear one
+    step_items(&mut world, 1);
+    assert!((alpha(&world, linear) - 0.25).abs() < 1e-5);
+    assert!((alpha(&world, eased) - 0.0625).abs() < 1e-5);
+    assert_ne!(alpha(&world, linear), alpha(&world, eased));
+}
+
+#[test]
+fn text_sections_fade_together() {
+    let mut world = World::new();
+    let style = TextStyle {
+        color: Color::WHITE,
+        ..Default::default()
+    };
+    let text = world.spawn()
+        .insert_bundle((
+            Text::from_sections([
+                TextSection::new("iyes", style.clone()),
+                TextSection::new("splash", style),
+            ]),
+            SplashItemFade::new(0.0, 0.4, 1.0, 0.4),
+        ))
+        .id();
+
+    step_items(&mut world, 1);
+    let sections = &world.get::<Text>(text).unwrap().sections;
+    assert!((sections[0].style.color.a() - 0.25).abs() < 1e-5);
+    assert_eq!(sections[0].style.color.a(), sections[1].style.color.a());
+}
+
+#[test]
+fn scale_ends_on_end_scale() {
+    let mut world = World::new();
+    let end_scale = Vec3::new(2.0, 3.0, 1.0);
+    let item = world.spawn()
+        .insert_bundle((
+            Transform::default(),
+            SplashItemScale::new(Vec3::ONE, end_scale, 0.5),
+        ))
+        .id();
+
+    step_items(&mut world, 8);
+    assert_eq!(world.get::<Transform>(item).unwrap().scale, end_scale);
+}
+
+#[test]
+fn skip_waits_for_min_duration() {
+    let mut app = app();
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .min_duration(Duration::from_secs(2))
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 1);
+    tap_key(&mut app, KeyCode::Space);
+    // the skip is remembered, but only happens once 2s have passed
+    update(&mut app, 15);
+    assert_eq!(state(&app), TestState::Splash);
+    update(&mut app, 3);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn skip_keys_limits_the_keys() {
+    let mut app = app();
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .skip_keys([KeyCode::Space])
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 1);
+    tap_key(&mut app, KeyCode::A);
+    assert_eq!(state(&app), TestState::Splash);
+    tap_key(&mut app, KeyCode::Space);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn disabled_keyboard_still_allows_mouse() {
+    let mut app = app();
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .skip_keyboard(false)
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 1);
+    tap_key(&mut app, KeyCode::Space);
+    assert_eq!(state(&app), TestState::Splash);
+    click(&mut app);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn hold_released_early_does_not_skip() {
+    let mut app = app();
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .hold_to_skip(Duration::from_millis(500))
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 2);
+    hold_key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    update(&mut app, 4);
+    assert_eq!(app.world.resource::<SplashSkipHold>().held, FRAME * 4);
+    hold_key(&mut app, KeyCode::Space, ButtonState::Released);
+    update(&mut app, 1);
+    assert_eq!(app.world.resource::<SplashSkipHold>().held, Duration::ZERO);
+
+    // a new hold starts over
+    hold_key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    update(&mut app, 4);
+    assert_eq!(state(&app), TestState::Splash);
+    update(&mut app, 2);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn pausing_freezes_the_splash() {
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || {
+        (Sprite::default(), SplashItemFade::new(0.0, 1.0, 1.0, 1.0))
+    });
+    let sprite_alpha = |app: &mut App| {
+        app.world.query::<&Sprite>().single(&app.world).color.a()
+    };
+
+    update(&mut app, 5);
+    app.insert_resource(SplashPaused);
+    update(&mut app, 1);
+    let alpha = sprite_alpha(&mut app);
+    let elapsed = app.world.resource::<SplashTime>().elapsed;
+    assert!(alpha > 0.0 && alpha < 1.0);
+    update(&mut app, 3);
+    assert_eq!(sprite_alpha(&mut app), alpha);
+    assert_eq!(app.world.resource::<SplashTime>().elapsed, elapsed);
+
+    app.world.remove_resource::<SplashPaused>();
+    update(&mut app, 1);
+    assert!(sprite_alpha(&mut app) > alpha);
+    assert!(app.world.resource::<SplashTime>().elapsed > elapsed);
+}
+
+#[cfg(feature = "iyes_progress")]
+#[test]
+fn progress_is_reported_with_the_weight() {
+    use iyes_progress::prelude::*;
+
+    let mut app = app();
+    app.add_plugin(ProgressPlugin::new(TestState::Splash).continue_to(TestState::Menu));
+    app.add_plugin(
+        SplashProgressPlugin::new(TestState::Splash)
+            .clock(SplashClock::Virtual)
+            .progress_weight(4)
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(0.5),));
+    let progress = |app: &App| {
+        let progress = app.world.resource::<ProgressCounter>().progress();
+        (progress.done, progress.total)
+    };
+
+    update(&mut app, 1);
+    assert_eq!(progress(&app), (0, 4));
+    let mut last = progress(&app);
+    while state(&app) == TestState::Splash {
+        last = progress(&app);
+        update(&mut app, 1);
+    }
+    assert_eq!(last, (4, 4));
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn ordered_groups_play_one_after_the_other() {
+    let mut world = World::new();
+    let first = world.spawn()
+        .insert_bundle((Sprite::default(), SplashItemFade::new(0.0, 0.5, 0.0, 0.0)))
+        .id();
+    let second = world.spawn()
+        .insert_bundle((
+            Sprite::default(),
+            SplashItemFade::new(0.0, 0.5, 1.0, 0.0),
+            SplashItemOrder(1),
+        ))
+        .id();
+
+    let mut stage = item_stage();
+    insert_manual_time(&mut world);
+    let mut frames = 0;
+    while world.get::<SplashItemFade>(first).unwrap().progress() < 1.0 {
+        step(&mut world, &mut stage);
+        frames += 1;
+        assert_eq!(alpha(&world, second), 0.0);
+        assert_eq!(world.get::<SplashItemFade>(second).unwrap().progress(), 0.0);
+    }
+    assert_eq!(frames, 5);
+
+    // the second group starts once the first one has finished
+    for _ in 0..5 {
+        step(&mut world, &mut stage);
+    }
+    assert!(alpha(&world, second) > 0.0);
+}
+
+#[test]
+fn blink_finishes_after_its_cycles() {
+    let mut world = World::new();
+    world.spawn().insert_bundle((Sprite::default(), SplashItemBlink::new(0.5).with_cycles(2)));
+    assert_eq!(frames_until_finished(&mut world), 10);
+    let mut q = world.query::<(&Sprite, &SplashItemBlink)>();
+    let (sprite, blink) = q.single(&world);
+    assert!(blink.finished());
+    assert_eq!(sprite.color.a(), 1.0);
+}
+
+#[test]
+fn zero_duration_phases_are_skipped_over() {
+    let ms = Duration::from_millis;
+
+    // no on or fade phase: fully visible for one frame, then vanishes
+    let mut fade = SplashItemFade::new(0.0, 0.5, 0.0, 0.0);
+    assert_eq!(fade.advance(ms(250)), (Some(0.5), false));
+    assert_eq!(fade.advance(ms(250)), (Some(1.0), false));
+    assert_eq!(fade.advance(ms(250)), (Some(0.0), true));
+    assert_eq!(fade.advance(ms(250)), (None, true));
+
+    // no intro: fully visible immediately
+    let mut fade = SplashItemFade::new(0.0, 0.0, 0.5, 0.5);
+    assert_eq!(fade.advance(Duration::ZERO), (Some(1.0), false));
+    assert_eq!(fade.advance(ms(750)), (Some(0.5), false));
+
+    // nothing at all: still shown for one frame, even without time passing
+    let mut fade = SplashItemFade::new(0.0, 0.0, 0.0, 0.0);
+    assert_eq!(fade.advance(Duration::ZERO), (Some(1.0), false));
+    assert_eq!(fade.advance(Duration::ZERO), (Some(0.0), true));
+}
+
+/// Compare colors in linear space, allowing for conversion rounding
+fn assert_color_eq(left: Color, right: Color) {
+    let (left, right) = (left.as_linear_rgba_f32(), right.as_linear_rgba_f32());
+    assert!(
+        left.iter().zip(right).all(|(l, r)| (l - r).abs() < 1e-6),
+        "{:?} != {:?}", left, right
+    );
+}
+
+fn color_round_trip(mut target: impl SplashColorTarget) {
+    target.set_color(Color::rgba(0.25, 0.5, 0.75, 1.0));
+    target.set_alpha(0.25);
+    assert_eq!(target.get_color(), Color::rgba(0.25, 0.5, 0.75, 0.25));
+    target.apply_fade(0.5, None, None);
+    assert_eq!(target.get_color(), Color::rgba(0.25, 0.5, 0.75, 0.5));
+    target.apply_fade(0.5, Some(Color::rgba(1.0, 0.0, 0.0, 0.5)), None);
+    assert_eq!(target.get_color(), Color::rgba(1.0, 0.0, 0.0, 0.25));
+}
+
+#[test]
+fn color_targets_round_trip_alpha() {
+    color_round_trip(Sprite::default());
+    color_round_trip(UiColor::default());
+    color_round_trip(TextSection::default());
+    color_round_trip(ColorMaterial::default());
+}
+
+#[test]
+fn skip_all_ends_on_the_first_frame() {
+    let mut app = app();
+    app.insert_resource(SkipAllSplashes(true));
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .min_duration(Duration::from_secs(5))
+    );
+
+    update(&mut app, 1);
+    assert_eq!(completed(&app), [SplashEndReason::Skipped]);
+    assert!(app.world.resource::<Events<SplashSkipped<TestState>>>().is_empty());
+    assert_eq!(app.world.query::<&Camera>().iter(&app.world).count(), 0);
+    update(&mut app, 1);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn skip_input_does_not_leak_into_the_next_state() {
+    /// What the next state saw of the skip key, on its first frame
+    #[derive(Default)]
+    struct FirstFrame(Option<(usize, bool, bool)>);
+
+    // transition right after the splash screen ends, within the same frame
+    let mut app = app_without_state();
+    app.add_loopless_state_after_stage(CoreStage::Update, TestState::Splash);
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+    app.init_resource::<FirstFrame>();
+    app.add_system_to_stage(
+        CoreStage::PostUpdate,
+        (|mut first: ResMut<FirstFrame>, mut evr: EventReader<KeyboardInput>, keys: Res<Input<KeyCode>>| {
+            first.0.get_or_insert((
+                evr.iter().count(),
+                keys.just_pressed(KeyCode::Space),
+                keys.pressed(KeyCode::Space),
+            ));
+        }).run_in_state(TestState::Menu)
+    );
+
+    update(&mut app, 2);
+    hold_key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    while state(&app) == TestState::Splash {
+        update(&mut app, 1);
+    }
+    // no events and no fresh press, but the key is still held down
+    assert_eq!(app.world.resource::<FirstFrame>().0, Some((0, false, true)));
+}
+
+#[test]
+fn gamepad_skips_past_the_threshold() {
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 1);
+    pull_trigger(&mut app, 0.1);
+    update(&mut app, 1);
+    assert_eq!(state(&app), TestState::Splash);
+    pull_trigger(&mut app, 0.6);
+    update(&mut app, 1);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn color_is_interpolated_during_the_intro() {
+    let mut fade = SplashItemFade::new(0.0, 0.5, 1.0, 0.0)
+        .with_color(Color::rgba(0.0, 0.0, 0.0, 1.0), Color::rgba(1.0, 0.5, 0.25, 1.0));
+    let (alpha, _) = fade.advance(Duration::from_millis(250));
+    let mut sprite = Sprite::default();
+    sprite.apply_fade(alpha.unwrap(), fade.tint(), None);
+    assert_eq!(sprite.color, Color::rgba(0.5, 0.25, 0.125, 0.5));
+}
+
+#[test]
+fn time_scale_speeds_up_items() {
+    let mut world = World::new();
+    world.spawn().insert(SplashItemTimeout::new(1.0));
+    assert_eq!(frames_until_finished(&mut world), 10);
+
+    let mut world = World::new();
+    world.insert_resource(SplashTimeScale(2.0));
+    world.spawn().insert(SplashItemTimeout::new(1.0));
+    assert_eq!(frames_until_finished(&mut world), 5);
+}
+
+#[test]
+fn huge_time_scale_finishes_on_the_next_frame() {
+    for scale in [1e30, f32::INFINITY] {
+        let mut world = World::new();
+        world.insert_resource(SplashTimeScale(scale));
+        world.spawn().insert(SplashItemTimeout::new(1.0));
+        assert_eq!(frames_until_finished(&mut world), 1);
+        assert_eq!(world.resource::<SplashTime>().delta, SplashTimeScale::MAX_STEP);
+    }
+}
+
+#[test]
+fn virtual_clock_follows_paused_time() {
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(0.5),));
+
+    update(&mut app, 2);
+    let elapsed = app.world.resource::<SplashState>().elapsed;
+    update_paused(&mut app, 20);
+    assert_eq!(app.world.resource::<SplashState>().elapsed, elapsed);
+    assert_eq!(state(&app), TestState::Splash);
+
+    update(&mut app, 10);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn max_duration_ends_an_endless_splash() {
+    let mut app = app();
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .max_duration(Duration::from_secs(1))
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || {
+        (Sprite::default(), SplashItemBlink::new(0.5))
+    });
+
+    update(&mut app, 1);
+    while completed(&app).is_empty() {
+        assert!(app.world.resource::<SplashState>().elapsed < Duration::from_secs(1));
+        update(&mut app, 1);
+    }
+    // on the first frame past `max_duration`
+    assert_eq!(completed(&app), [SplashEndReason::TimedOut]);
+    let elapsed = app.world.resource::<SplashState>().elapsed;
+    assert!(elapsed >= Duration::from_secs(1) && elapsed < Duration::from_secs(1) + FRAME);
+    update(&mut app, 1);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn optional_items_do_not_hold_up_the_splash() {
+    let mut world = World::new();
+    world.spawn().insert(SplashItemTimeout::new(1.0));
+    world.spawn().insert_bundle((SplashItemTimeout::new(5.0), SplashItemOptional));
+    assert_eq!(frames_until_finished(&mut world), 10);
+}
+
+#[test]
+fn fade_out_stops_at_min_alpha() {
+    let mut world = World::new();
+    let item = world.spawn()
+        .insert_bundle((
+            Sprite::default(),
+            SplashItemFade::new(0.0, 0.0, 0.25, 0.5).with_min_alpha(0.25),
+        ))
+        .id();
+
+    let mut stage = item_stage();
+    insert_manual_time(&mut world);
+    let mut lowest = 1.0f32;
+    for _ in 0..10 {
+        step(&mut world, &mut stage);
+        lowest = lowest.min(alpha(&world, item));
+    }
+    assert_eq!(lowest, 0.25);
+    assert_eq!(alpha(&world, item), 0.25);
+}
+
+#[test]
+fn fade_progress_is_shared_between_phases() {
+    let mut world = World::new();
+    let item = world.spawn()
+        .insert_bundle((Sprite::default(), SplashItemFade::new(0.25, 0.25, 0.25, 0.25)))
+        .id();
+
+    let mut stage = item_stage();
+    insert_manual_time(&mut world);
+    let ms = Duration::from_millis;
+    for (delta, expected) in [(ms(250), 0.25), (ms(250), 0.5), (ms(500), 1.0)] {
+        step_by(&mut world, &mut stage, delta);
+        assert_eq!(world.get::<SplashItemProgress>(item).unwrap().get(), expected);
+    }
+}
+
+#[test]
+fn connecting_a_gamepad_does_not_skip() {
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 2);
+    app.world.send_event(GamepadEvent::new(Gamepad::new(0), GamepadEventType::Connected));
+    update(&mut app, 2);
+    assert_eq!(state(&app), TestState::Splash);
+    assert!(!app.world.resource::<SplashState>().skip_requested);
+}
+
+#[test]
+fn yoyo_fades_back_in() {
+    let mut fade = SplashItemFade::new(0.0, 0.25, 0.0, 0.25).with_yoyo(1);
+    let steps: Vec<_> = (0..8).map(|_| fade.advance(Duration::from_millis(125))).collect();
+    assert_eq!(steps, [
+        (Some(0.5), false),
+        (Some(1.0), false),
+        (Some(0.5), false),
+        // the second cycle starts over from the intro
+        (Some(0.0), false),
+        (Some(0.5), false),
+        (Some(1.0), false),
+        (Some(0.5), false),
+        (Some(0.0), true),
+    ]);
+    assert_eq!(fade.advance(Duration::from_millis(125)), (None, true));
+}
+
+#[test]
+fn follower_waits_for_the_intro() {
+    let mut world = World::new();
+    let a = world.spawn()
+        .insert_bundle((Sprite::default(), SplashItemFade::new(0.0, 0.5, 1.0, 0.5)))
+        .id();
+    let b = world.spawn()
+        .insert_bundle((
+            Sprite::default(),
+            SplashItemFade::new(0.0, 0.5, 1.0, 0.5),
+            SplashItemFollow(a),
+        ))
+        .id();
+
+    let mut stage = item_stage();
+    insert_manual_time(&mut world);
+    for _ in 0..4 {
+        step(&mut world, &mut stage);
+        assert!(alpha(&world, a) < 1.0);
+        assert_eq!(alpha(&world, b), 0.0);
+    }
+    // `a` is fully visible, so `b` starts fading in
+    step(&mut world, &mut stage);
+    assert_eq!(alpha(&world, a), 1.0);
+    assert!(alpha(&world, b) < 0.01);
+    step(&mut world, &mut stage);
+    assert!((alpha(&world, b) - 0.2).abs() < 0.01);
+}
+
+#[test]
+fn confirm_skip_needs_a_second_press() {
+    let mut app = app();
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .confirm_skip(Duration::from_millis(500))
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 2);
+    tap_key(&mut app, KeyCode::Space);
+    assert!(app.world.resource::<SplashSkipConfirm>().pending());
+    // too late for the second press
+    update(&mut app, 10);
+    assert!(!app.world.resource::<SplashSkipConfirm>().pending());
+    tap_key(&mut app, KeyCode::Space);
+    assert_eq!(state(&app), TestState::Splash);
+
+    tap_key(&mut app, KeyCode::Space);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn frame_fades_count_frames() {
+    let mut world = World::new();
+    world.spawn().insert_bundle((Sprite::default(), SplashItemFade::frames(0, 3, 2, 3)));
+
+    // however long each frame takes
+    let mut stage = item_stage();
+    insert_manual_time(&mut world);
+    for frame in 1..=8 {
+        step_by(&mut world, &mut stage, Duration::from_millis(frame * 37));
+        assert_eq!(world.resource::<SplashItems>().all_finished(), frame == 8);
+    }
+}
+
+#[test]
+fn splash_screens_on_two_states_run_both() {
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Second));
+    app.add_plugin(splash(TestState::Second, TestState::Menu));
+    // a duplicate for the same state is ignored
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(0.5),));
+    spawn_on_enter(&mut app, TestState::Second, || (SplashItemTimeout::new(1.0),));
+
+    update(&mut app, 7);
+    assert_eq!(state(&app), TestState::Second);
+    update(&mut app, 7);
+    assert_eq!(state(&app), TestState::Second);
+    update(&mut app, 4);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn premultiplied_fade_scales_the_color() {
+    // (components other than 0 and 1 differ between sRGB and linear)
+    let base = Color::rgba_linear(1.0, 0.5, 0.25, 1.0);
+    let mut sprite = Sprite {
+        color: base,
+        ..Default::default()
+    };
+    sprite.apply_fade(0.5, None, Some(base));
+    assert_color_eq(sprite.color, Color::rgba_linear(0.5, 0.25, 0.125, 0.5));
+}
+
+#[test]
+fn run_condition_follows_the_skip() {
+    #[derive(Default)]
+    struct Runs(u32);
+
+    let mut app = app();
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .min_duration(Duration::from_secs(2))
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+    app.init_resource::<Runs>();
+    app.add_system((|mut runs: ResMut<Runs>| runs.0 += 1).run_if(splash_skip_requested));
+
+    update(&mut app, 3);
+    assert_eq!(app.world.resource::<Runs>().0, 0);
+    tap_key(&mut app, KeyCode::Space);
+    update(&mut app, 3);
+    assert!(app.world.resource::<SplashSkipRequested>().get());
+    assert!(app.world.resource::<Runs>().0 >= 3);
+
+    // not once the splash screen is over
+    update(&mut app, 20);
+    assert_eq!(state(&app), TestState::Menu);
+    let runs = app.world.resource::<Runs>().0;
+    update(&mut app, 3);
+    assert_eq!(app.world.resource::<Runs>().0, runs);
+}
+
+#[test]
+fn manual_splash_does_not_transition() {
+    let mut app = app();
+    app.add_plugin(SplashPlugin::manual(TestState::Splash).clock(SplashClock::Virtual));
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(0.5),));
+
+    let mut reasons = Vec::new();
+    for _ in 0..20 {
+        update(&mut app, 1);
+        reasons.extend(completed(&app));
+        assert!(app.world.get_resource::<NextState<TestState>>().is_none());
+    }
+    // (each event is seen on two updates)
+    assert_eq!(reasons, [SplashEndReason::Completed; 2]);
+    assert_eq!(state(&app), TestState::Splash);
+}
+
+#[cfg(feature = "iyes_progress")]
+#[test]
+fn skip_waits_for_other_progress() {
+    use iyes_progress::prelude::*;
+
+    /// Units of other work done, out of 4
+    struct Work(u32);
+
+    let mut app = app();
+    app.add_plugin(ProgressPlugin::new(TestState::Splash));
+    app.add_plugin(
+        SplashProgressPlugin::new(TestState::Splash)
+            .clock(SplashClock::Virtual)
+            .skip_min_progress(0.5)
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+    app.insert_resource(Work(1));
+    app.add_system(
+        (|work: Res<Work>| Progress { done: work.0, total: 4 })
+            .track_progress()
+            .run_in_state(TestState::Splash)
+    );
+
+    update(&mut app, 2);
+    tap_key(&mut app, KeyCode::Space);
+    update(&mut app, 5);
+    assert!(app.world.resource::<SplashSkipRequested>().get());
+    assert!(completed(&app).is_empty());
+
+    app.insert_resource(Work(2));
+    update(&mut app, 2);
+    assert_eq!(completed(&app), [SplashEndReason::Skipped]);
+}
+
+#[test]
+fn types_are_registered() {
+    use bevy::reflect::TypeRegistryArc;
+    use std::any::TypeId;
+
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    let registry = app.world.resource::<TypeRegistryArc>().read();
+    for id in [
+        TypeId::of::<SplashItemFade>(),
+        TypeId::of::<SplashItemTimeout>(),
+        TypeId::of::<SplashItemRepeat>(),
+    ] {
+        assert!(registry.get_type_data::<ReflectComponent>(id).is_some());
+    }
+    for id in [
+        TypeId::of::<SkipAllSplashes>(),
+        TypeId::of::<SplashPaused>(),
+        TypeId::of::<SplashTimeScale>(),
+        TypeId::of::<SplashTestClock>(),
+    ] {
+        assert!(registry.get_type_data::<ReflectResource>(id).is_some());
+    }
+}
+
+#[test]
+fn keyframes_become_phase_durations() {
+    let fade = SplashItemFade::keyframes(0.25, 0.5, 1.0, 1.5).unwrap();
+    let durations = [&fade.timer_wait, &fade.timer_intro, &fade.timer_on, &fade.timer_fade]
+        .map(|timer| timer.duration().as_secs_f32());
+    assert_eq!(durations, [0.25, 0.25, 0.5, 0.5]);
+}
+
+#[test]
+fn bad_keyframes_are_rejected() {
+    assert_eq!(
+        SplashItemFade::keyframes(0.5, 0.25, 1.0, 1.5).err(),
+        Some(SplashKeyframesError::OutOfOrder([0.5, 0.25, 1.0, 1.5])),
+    );
+    assert!(matches!(
+        SplashItemFade::keyframes(0.0, 0.5, f32::NAN, 1.5),
+        Err(SplashKeyframesError::Invalid(_)),
+    ));
+    assert!(matches!(
+        SplashItemFade::keyframes(-0.5, 0.5, 1.0, 1.5),
+        Err(SplashKeyframesError::Invalid(_)),
+    ));
+}
+
+#[test]
+fn systems_run_in_the_custom_stage() {
+    use bevy::ecs::schedule::ShouldRun;
+
+    #[derive(StageLabel)]
+    struct SplashStage;
+    struct RunSplash(bool);
+
+    let mut app = app();
+    app.insert_resource(RunSplash(false));
+    app.add_stage_after(
+        CoreStage::Update,
+        SplashStage,
+        SystemStage::parallel().with_run_criteria(|run: Res<RunSplash>| {
+            if run.0 { ShouldRun::Yes } else { ShouldRun::No }
+        }),
+    );
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .stage(SplashStage)
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(0.5),));
+
+    // nothing happens, while the stage does not run
+    update(&mut app, 10);
+    assert_eq!(app.world.resource::<SplashState>().elapsed, Duration::ZERO);
+    assert_eq!(state(&app), TestState::Splash);
+
+    app.insert_resource(RunSplash(true));
+    update(&mut app, 5);
+    assert_eq!(state(&app), TestState::Splash);
+    update(&mut app, 3);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn huge_step_lands_on_min_alpha() {
+    let mut app = app();
+    app.insert_resource(SplashTestClock(Duration::from_secs(60)));
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || {
+        (Sprite::default(), SplashItemFade::new(0.1, 0.3, 0.7, 0.2).with_min_alpha(0.25))
+    });
+
+    update(&mut app, 1);
+    let mut q = app.world.query::<(&Sprite, &SplashItemProgress)>();
+    let (sprite, progress) = q.single(&app.world);
+    assert_eq!(sprite.color.a(), 0.25);
+    assert_eq!(progress.get(), 1.0);
+    update(&mut app, 1);
+    assert_eq!(completed(&app), [SplashEndReason::Completed]);
+}
+
+#[test]
+fn focus_loss_pauses_until_focus_returns() {
+    use bevy::window::{WindowFocused, WindowId};
+
+    let focus = |app: &mut App, focused| {
+        app.world.send_event(WindowFocused { id: WindowId::primary(), focused });
+        update(app, 1);
+    };
+    let elapsed = |app: &App| app.world.resource::<SplashState>().elapsed;
+
+    let mut app = app();
+    app.add_event::<WindowFocused>();
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .on_focus_loss(SplashFocusLoss::Pause)
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 2);
+    focus(&mut app, false);
+    update(&mut app, 1);
+    assert!(app.world.contains_resource::<SplashPaused>());
+    let paused_at = elapsed(&app);
+    update(&mut app, 3);
+    assert_eq!(elapsed(&app), paused_at);
+
+    focus(&mut app, true);
+    assert!(!app.world.contains_resource::<SplashPaused>());
+    update(&mut app, 1);
+    assert!(elapsed(&app) > paused_at);
+}
+
+#[test]
+fn intro_waits_for_the_image() {
+    use bevy::asset::HandleId;
+
+    let mut app = app();
+    app.add_plugin(bevy::asset::AssetPlugin);
+    app.add_asset::<Image>();
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    let image = app.world.resource_mut::<Assets<Image>>().get_handle(HandleId::random::<Image>());
+    let handle = image.clone();
+    spawn_on_enter(&mut app, TestState::Splash, move || {
+        (Sprite::default(), handle.clone(), SplashItemFade::new(0.0, 0.5, 1.0, 0.5))
+    });
+    let sprite_alpha = |app: &mut App| {
+        app.world.query::<&Sprite>().single(&app.world).color.a()
+    };
+
+    update(&mut app, 10);
+    assert_eq!(sprite_alpha(&mut app), 0.0);
+    assert_eq!(state(&app), TestState::Splash);
+
+    app.world.resource_mut::<Assets<Image>>().set_untracked(image.id, Image::default());
+    update(&mut app, 2);
+    assert!(sprite_alpha(&mut app) > 0.0);
+}
+
+#[test]
+fn until_skipped_waits_for_a_skip() {
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || {
+        (Sprite::default(), SplashItemFade::until_skipped(0.0, 0.2))
+    });
+
+    // FRAME is 0.1s: a minute of splash screen
+    update(&mut app, 600);
+    assert_eq!(state(&app), TestState::Splash);
+    let alpha = app.world.query::<&Sprite>().single(&app.world).color.a();
+    assert_eq!(alpha, 1.0);
+    assert_eq!(app.world.resource::<SplashState>().remaining, None);
+
+    tap_key(&mut app, KeyCode::Space);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn cut_style_skips_the_fade() {
+    let mut world = World::new();
+    let item = world.spawn()
+        .insert_bundle((
+            Sprite::default(),
+            SplashItemFade::new(0.0, 0.0, 0.25, 0.5)
+                .with_fade_out_style(FadeOutStyle::Cut),
+        ))
+        .id();
+
+    let mut stage = item_stage();
+    insert_manual_time(&mut world);
+    let mut alphas = Vec::new();
+    for _ in 0..10 {
+        step(&mut world, &mut stage);
+        alphas.push(alpha(&world, item));
+    }
+    assert!(alphas.iter().all(|&a| a == 0.0 || a == 1.0), "{:?}", alphas);
+    assert_eq!(alphas.first(), Some(&1.0));
+    assert_eq!(alphas.last(), Some(&0.0));
+}
+
+#[test]
+fn same_seed_gives_the_same_stagger() {
+    let waits = |seed| {
+        let mut stagger = SplashStagger::new(seed, 0.25..0.75);
+        (0..8).map(|_| stagger.next_wait()).collect::<Vec<_>>()
+    };
+    assert_eq!(waits(42), waits(42));
+    assert_ne!(waits(42), waits(43));
+    assert!(waits(42).iter().all(|wait| (0.25..0.75).contains(wait)));
+
+    let mut stagger = SplashStagger::new(42, 0.25..0.75);
+    let fade = SplashItemFade::standard().with_stagger(&mut stagger);
+    assert_eq!(fade.timer_wait.duration(), Duration::from_secs_f32(waits(42)[0]));
+}
+
+#[test]
+fn unscaled_steps_are_exact() {
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 3);
+    assert_eq!(app.world.resource::<SplashState>().elapsed, FRAME * 3);
+}
+
+#[test]
+fn item_spawned_as_others_finish_is_counted() {
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemFade::new(0.0, 0.25, 0.0, 0.25),));
+    // on the frame the fade finishes, spawn another item,
+    // which only takes effect after the frame's completion check
+    app.add_system(
+        (|mut commands: Commands, mut frame: Local<u32>| {
+            *frame += 1;
+            if *frame == 5 {
+                commands.spawn_splash_item((SplashItemTimeout::new(0.25),));
+            }
+        })
+        .run_in_state(TestState::Splash)
+        .after(SplashSet::Animate)
+        .before(SplashSet::Complete)
+    );
+
+    update(&mut app, 8);
+    assert!(completed(&app).is_empty());
+    assert_eq!(state(&app), TestState::Splash);
+    update(&mut app, 1);
+    assert_eq!(completed(&app), vec![SplashEndReason::Completed]);
+}
+
+#[test]
+fn infinite_blink_can_be_skipped() {
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || {
+        (Sprite::default(), SplashItemBlink::new(0.5))
+    });
+
+    update(&mut app, 2);
+    tap_key(&mut app, KeyCode::Space);
+    assert_eq!(completed(&app), vec![SplashEndReason::Skipped]);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn hold_uses_splash_time() {
+    let mut app = app();
+    app.insert_resource(SplashTimeScale(2.0));
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .hold_to_skip(Duration::from_millis(700))
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 2);
+    hold_key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    update(&mut app, 3);
+    assert!(completed(&app).is_empty());
+    update(&mut app, 1);
+    assert_eq!(completed(&app), vec![SplashEndReason::Skipped]);
+}
+
+#[test]
+fn zero_hold_needs_a_press() {
+    let mut app = app();
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .hold_to_skip(Duration::ZERO)
+    );
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::new(5.0),));
+
+    update(&mut app, 5);
+    assert_eq!(state(&app), TestState::Splash);
+    tap_key(&mut app, KeyCode::Space);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn timeout_waits_for_repetitions() {
+    let mut app = app();
+    app.add_plugin(splash(TestState::Splash, TestState::Menu));
+    spawn_on_enter(&mut app, TestState::Splash, || {
+        (SplashItemTimeout(Timer::from_seconds(0.5, true)), SplashItemRepeat::new(3))
+    });
+    spawn_on_enter(&mut app, TestState::Splash, || (SplashItemTimeout::from(Timer::from_seconds(0.5, false)),));
+
+    update(&mut app, 14);
+    let mut q = app.world.query::<(&SplashItemTimeout, &SplashItemRepeat)>();
+    let (timeout, repeat) = q.single(&app.world);
+    assert!(!repeat.finished());
+    assert_eq!(repeat.remaining(&timeout.0), FRAME);
+    update(&mut app, 1);
+    let (_, repeat) = q.single(&app.world);
+    assert!(repeat.finished());
+    assert_eq!(state(&app), TestState::Splash);
+    update(&mut app, 2);
+    assert_eq!(state(&app), TestState::Menu);
+}
+
+#[test]
+fn skip_once_visible_ignores_skips_during_wait() {
+    let mut app = app();
+    app.add_plugin(
+        splash(TestState::Splash, TestState::Menu)
+            .skip_once_visible(true)
+    );
+    // visible from the start, but not yet fading in
+    spawn_on_enter(&mut app, TestState::Splash, || {
+        (Sprite::default(), SplashItemFade::new(0.5, 0.5, 1.0, 0.5).with_min_alpha(0.25))
+    });
+
+    update(&mut app, 1);
+    tap_key(&mut app, KeyCode::Space);
+    tap_key(&mut app, KeyCode::Space);
+    assert_eq!(state(&app), TestState::Splash);
+    update(&mut app, 1);
+    tap_key(&mut app, KeyCode::Space);
+    assert_eq!(completed(&app), vec![SplashEndReason::Skipped]);
+}
+
+/// Counts the errors logged while it is the default subscriber
+#[derive(Clone, Default)]
+struct ErrorCounter(Arc<AtomicUsize>);
+
+impl ErrorCounter {
+    fn count(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+impl tracing::Subscriber for ErrorCounter {
+    fn enabled(&self, _: &tracing::Metadata) -> bool {
+        true
+    }
+    fn new_span(&self, _: &span::Attributes) -> span::Id {
+        span::Id::from_u64(1)
+    }
+    fn record(&self, _: &span::Id, _: &span::Record) {}
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+    fn event(&self, event: &tracing::Event) {
+        if *event.metadata().level() == tracing::Level::ERROR {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+    fn enter(&self, _: &span::Id) {}
+    fn exit(&self, _: &span::Id) {}
+}
+
+#[test]
+fn transition_to_itself_logs_an_error() {
+    let errors_for = |plugin: SplashPlugin<TestState>| {
+        let errors = ErrorCounter::default();
+        let mut app = app();
+        tracing::subscriber::with_default(errors.clone(), || {
+            app.add_plugin(plugin);
+        });
+        errors.count()
+    };
+    assert_eq!(errors_for(splash(TestState::Splash, TestState::Splash)), 1);
+    assert_eq!(errors_for(splash(TestState::Splash, TestState::Menu)), 0);
+    assert_eq!(errors_for(SplashPlugin::manual(TestState::Splash)), 0);
+}
+
+#[test]
+fn hover_scale_grows_back_from_zero() {
+    let mut hover = SplashItemHover::new(10.0).with_scale(0.0);
+    let base = Vec3::new(2.0, 3.0, 1.0);
+    assert_eq!(hover.rescale(base), None);
+
+    hover.amount = 1.0;
+    let shrunk = hover.rescale(base).unwrap();
+    assert_eq!(shrunk, Vec3::new(0.0, 0.0, 1.0));
+    hover.amount = 0.5;
+    assert_eq!(hover.rescale(shrunk), Some(Vec3::new(1.0, 1.5, 1.0)));
+    hover.amount = 0.0;
+    assert_eq!(hover.rescale(Vec3::new(1.0, 1.5, 1.0)), Some(base));
+}
+
+#[test]
+#[should_panic(expected = "has not been added")]
+fn missing_state_panics() {
+    app_without_state().add_plugin(splash(TestState::Splash, TestState::Menu));
+}